@@ -0,0 +1,168 @@
+#ifndef BOJA_H
+#define BOJA_H
+
+/* Generated by cbindgen from src/lib.rs, run `BOJA_UPDATE_HEADER=1 cargo test -p boja-capi` after changing it. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Result of a function of the C interface.
+ */
+typedef enum BojaStatus {
+  BOJA_STATUS_OK = 0,
+  /**
+   * Required pointer argument is null.
+   */
+  BOJA_STATUS_NULL_POINTER,
+  /**
+   * Input is not valid UTF-8.
+   */
+  BOJA_STATUS_INVALID_UTF8,
+  /**
+   * Input is not a color in any of the supported formats.
+   */
+  BOJA_STATUS_INVALID_COLOR,
+  /**
+   * Output buffer cannot hold the result and terminating null character.
+   */
+  BOJA_STATUS_BUFFER_TOO_SMALL,
+  /**
+   * Format is not one of `BojaFormat`.
+   */
+  BOJA_STATUS_INVALID_FORMAT,
+  /**
+   * Field of `BojaOptions` is out of its range.
+   */
+  BOJA_STATUS_INVALID_OPTIONS,
+} BojaStatus;
+
+/**
+ * Output format of `boja_convert`, passed as `uint32_t`, see `boja --help`
+ * for examples.
+ */
+typedef enum BojaFormat {
+  BOJA_FORMAT_HEX,
+  BOJA_FORMAT_RGB,
+  BOJA_FORMAT_HEX48,
+  BOJA_FORMAT_RGB48,
+  BOJA_FORMAT_HSL,
+  BOJA_FORMAT_HSV,
+  BOJA_FORMAT_XYZ,
+  BOJA_FORMAT_XYY,
+  BOJA_FORMAT_OKLAB,
+  BOJA_FORMAT_OKLCH,
+  BOJA_FORMAT_SCRGB,
+  BOJA_FORMAT_PQ,
+  BOJA_FORMAT_HLG,
+  BOJA_FORMAT_ICTCP,
+  BOJA_FORMAT_JZAZBZ,
+  BOJA_FORMAT_JZCZHZ,
+  BOJA_FORMAT_COLOR,
+  BOJA_FORMAT_RUST,
+  BOJA_FORMAT_RUST_STRUCT,
+  BOJA_FORMAT_C,
+  BOJA_FORMAT_SWIFTUI,
+  BOJA_FORMAT_UIKIT,
+  BOJA_FORMAT_ANDROID,
+  BOJA_FORMAT_FLUTTER,
+  BOJA_FORMAT_UNITY,
+  BOJA_FORMAT_GLSL,
+  BOJA_FORMAT_GLSL_VEC4,
+  BOJA_FORMAT_COMPOSE,
+  BOJA_FORMAT_LATEX,
+} BojaFormat;
+
+/**
+ * Color with gamma encoded sRGB channels and alpha in range 0..1. Channels
+ * outside of the range represent colors outside of the sRGB gamut.
+ */
+typedef struct BojaColor {
+  double red;
+  double green;
+  double blue;
+  double alpha;
+} BojaColor;
+
+/**
+ * Options of `boja_convert_with`. Zeroed options select the defaults of the
+ * command line tool.
+ */
+typedef struct BojaOptions {
+  /**
+   * Number of decimal places in formats with decimal values, negative for
+   * the default of each format.
+   */
+  int32_t precision;
+  /**
+   * Chromaticity x of the reference white of XYZ based formats, e.g.
+   * 0.3457 for D50. Both coordinates of 0 select D65.
+   */
+  double white_x;
+  /**
+   * Chromaticity y of the reference white, greater than 0.
+   */
+  double white_y;
+} BojaOptions;
+
+/**
+ * Parses null terminated `input` in any supported format into `color`.
+ *
+ * # Safety
+ *
+ * `input` must be null or a valid null terminated string, `color` must be
+ * null or valid for writes.
+ */
+enum BojaStatus boja_parse(const char *input, struct BojaColor *color);
+
+/**
+ * Writes `color` in given `BojaFormat` as null terminated string into
+ * `buffer` of `capacity` bytes, using default options of the command line
+ * tool.
+ *
+ * Length of the output without the terminating null is written into
+ * `length` unless it is null, also when the buffer is too small, so that
+ * the required capacity can be queried with `capacity` of 0.
+ *
+ * # Safety
+ *
+ * `color` must be null or valid for reads, `buffer` must be valid for
+ * writes of `capacity` bytes, `length` must be null or valid for writes.
+ */
+enum BojaStatus boja_convert(const struct BojaColor *color,
+                             uint32_t format,
+                             char *buffer,
+                             size_t capacity,
+                             size_t *length);
+
+/**
+ * Same as `boja_convert`, with precision and white point given by
+ * `options`, or the defaults if it is null.
+ *
+ * # Safety
+ *
+ * `options` must be null or valid for reads, see `boja_convert` for the
+ * other arguments.
+ */
+enum BojaStatus boja_convert_with(const struct BojaColor *color,
+                                  uint32_t format,
+                                  const struct BojaOptions *options,
+                                  char *buffer,
+                                  size_t capacity,
+                                  size_t *length);
+
+/**
+ * Writes channels of `color` clamped to sRGB gamut as 8 bit red, green, blue
+ * and alpha into `rgba`.
+ *
+ * # Safety
+ *
+ * `color` must be null or valid for reads, `rgba` must be null or valid for
+ * writes of 4 bytes.
+ */
+enum BojaStatus boja_to_rgba8(const struct BojaColor *color, uint8_t *rgba);
+
+#endif  /* BOJA_H */
@@ -0,0 +1,504 @@
+//! C interface of boja.
+//!
+//! Colors are passed by value as [`BojaColor`]. Functions return
+//! [`BojaStatus`] and write their results through pointers given by the
+//! caller, e.g.:
+//!
+//! ```c
+//! BojaColor color;
+//! char output[64];
+//!
+//! if (boja_parse("#3366cc", &color) == BOJA_STATUS_OK) {
+//!     boja_convert(&color, BOJA_FORMAT_OKLCH, output, sizeof output, NULL);
+//! }
+//! ```
+//!
+//! Formats are passed as `uint32_t` rather than [`BojaFormat`], so that
+//! values outside of the enum are reported as
+//! [`BojaStatus::InvalidFormat`] instead of being undefined behavior.
+//!
+//! The header `include/boja.h` is generated by cbindgen, see
+//! `tests/header.rs`.
+
+use std::{
+    ffi::{c_char, CStr},
+    ptr,
+};
+
+use boja::{Color, Format, Options, WhitePoint};
+
+/// Color with gamma encoded sRGB channels and alpha in range 0..1. Channels
+/// outside of the range represent colors outside of the sRGB gamut.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BojaColor {
+    pub red: f64,
+    pub green: f64,
+    pub blue: f64,
+    pub alpha: f64,
+}
+
+impl From<Color> for BojaColor {
+    fn from(color: Color) -> Self {
+        Self {
+            red: color.red(),
+            green: color.green(),
+            blue: color.blue(),
+            alpha: color.alpha(),
+        }
+    }
+}
+
+impl From<BojaColor> for Color {
+    fn from(color: BojaColor) -> Self {
+        Color::new(color.red, color.green, color.blue, color.alpha)
+    }
+}
+
+/// Result of a function of the C interface.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BojaStatus {
+    Ok = 0,
+    /// Required pointer argument is null.
+    NullPointer,
+    /// Input is not valid UTF-8.
+    InvalidUtf8,
+    /// Input is not a color in any of the supported formats.
+    InvalidColor,
+    /// Output buffer cannot hold the result and terminating null character.
+    BufferTooSmall,
+    /// Format is not one of `BojaFormat`.
+    InvalidFormat,
+    /// Field of `BojaOptions` is out of its range.
+    InvalidOptions,
+}
+
+/// Output format of `boja_convert`, passed as `uint32_t`, see `boja --help`
+/// for examples.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BojaFormat {
+    Hex,
+    Rgb,
+    Hex48,
+    Rgb48,
+    Hsl,
+    Hsv,
+    Xyz,
+    Xyy,
+    Oklab,
+    Oklch,
+    Scrgb,
+    Pq,
+    Hlg,
+    Ictcp,
+    Jzazbz,
+    Jzczhz,
+    Color,
+    Rust,
+    RustStruct,
+    C,
+    Swiftui,
+    Uikit,
+    Android,
+    Flutter,
+    Unity,
+    Glsl,
+    GlslVec4,
+    Compose,
+    Latex,
+}
+
+/// All formats ordered by their value.
+const FORMATS: [BojaFormat; 29] = [
+    BojaFormat::Hex,
+    BojaFormat::Rgb,
+    BojaFormat::Hex48,
+    BojaFormat::Rgb48,
+    BojaFormat::Hsl,
+    BojaFormat::Hsv,
+    BojaFormat::Xyz,
+    BojaFormat::Xyy,
+    BojaFormat::Oklab,
+    BojaFormat::Oklch,
+    BojaFormat::Scrgb,
+    BojaFormat::Pq,
+    BojaFormat::Hlg,
+    BojaFormat::Ictcp,
+    BojaFormat::Jzazbz,
+    BojaFormat::Jzczhz,
+    BojaFormat::Color,
+    BojaFormat::Rust,
+    BojaFormat::RustStruct,
+    BojaFormat::C,
+    BojaFormat::Swiftui,
+    BojaFormat::Uikit,
+    BojaFormat::Android,
+    BojaFormat::Flutter,
+    BojaFormat::Unity,
+    BojaFormat::Glsl,
+    BojaFormat::GlslVec4,
+    BojaFormat::Compose,
+    BojaFormat::Latex,
+];
+
+impl TryFrom<u32> for BojaFormat {
+    type Error = BojaStatus;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        usize::try_from(value)
+            .ok()
+            .and_then(|index| FORMATS.get(index))
+            .copied()
+            .ok_or(BojaStatus::InvalidFormat)
+    }
+}
+
+impl From<BojaFormat> for Format {
+    fn from(format: BojaFormat) -> Self {
+        match format {
+            BojaFormat::Hex => Format::Hex,
+            BojaFormat::Rgb => Format::Rgb,
+            BojaFormat::Hex48 => Format::Hex48,
+            BojaFormat::Rgb48 => Format::Rgb48,
+            BojaFormat::Hsl => Format::Hsl,
+            BojaFormat::Hsv => Format::Hsv,
+            BojaFormat::Xyz => Format::Xyz,
+            BojaFormat::Xyy => Format::Xyy,
+            BojaFormat::Oklab => Format::Oklab,
+            BojaFormat::Oklch => Format::Oklch,
+            BojaFormat::Scrgb => Format::Scrgb,
+            BojaFormat::Pq => Format::Pq,
+            BojaFormat::Hlg => Format::Hlg,
+            BojaFormat::Ictcp => Format::Ictcp,
+            BojaFormat::Jzazbz => Format::Jzazbz,
+            BojaFormat::Jzczhz => Format::Jzczhz,
+            BojaFormat::Color => Format::Color,
+            BojaFormat::Rust => Format::Rust,
+            BojaFormat::RustStruct => Format::RustStruct,
+            BojaFormat::C => Format::C,
+            BojaFormat::Swiftui => Format::Swiftui,
+            BojaFormat::Uikit => Format::Uikit,
+            BojaFormat::Android => Format::Android,
+            BojaFormat::Flutter => Format::Flutter,
+            BojaFormat::Unity => Format::Unity,
+            BojaFormat::Glsl => Format::Glsl,
+            BojaFormat::GlslVec4 => Format::GlslVec4,
+            BojaFormat::Compose => Format::Compose,
+            BojaFormat::Latex => Format::Latex,
+        }
+    }
+}
+
+/// Options of `boja_convert_with`. Zeroed options select the defaults of the
+/// command line tool.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BojaOptions {
+    /// Number of decimal places in formats with decimal values, negative for
+    /// the default of each format.
+    pub precision: i32,
+    /// Chromaticity x of the reference white of XYZ based formats, e.g.
+    /// 0.3457 for D50. Both coordinates of 0 select D65.
+    pub white_x: f64,
+    /// Chromaticity y of the reference white, greater than 0.
+    pub white_y: f64,
+}
+
+impl TryFrom<BojaOptions> for Options {
+    type Error = BojaStatus;
+
+    fn try_from(options: BojaOptions) -> Result<Self, Self::Error> {
+        let white_point = match (options.white_x, options.white_y) {
+            (0.0, 0.0) => WhitePoint::D65,
+            (x, y) if (0.0..=1.0).contains(&x) && y > 0.0 && x + y <= 1.0 => {
+                WhitePoint::Custom(x, y)
+            }
+            _ => return Err(BojaStatus::InvalidOptions),
+        };
+
+        Ok(Options {
+            precision: usize::try_from(options.precision).ok(),
+            white_point,
+            ..Options::default()
+        })
+    }
+}
+
+/// Parses null terminated `input` in any supported format into `color`.
+///
+/// # Safety
+///
+/// `input` must be null or a valid null terminated string, `color` must be
+/// null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn boja_parse(input: *const c_char, color: *mut BojaColor) -> BojaStatus {
+    if input.is_null() || color.is_null() {
+        return BojaStatus::NullPointer;
+    }
+
+    let Ok(input) = CStr::from_ptr(input).to_str() else {
+        return BojaStatus::InvalidUtf8;
+    };
+
+    match boja::parse_color(input) {
+        Ok(parsed) => {
+            color.write(BojaColor::from(parsed));
+            BojaStatus::Ok
+        }
+        Err(_) => BojaStatus::InvalidColor,
+    }
+}
+
+/// Writes `color` in given `BojaFormat` as null terminated string into
+/// `buffer` of `capacity` bytes, using default options of the command line
+/// tool.
+///
+/// Length of the output without the terminating null is written into
+/// `length` unless it is null, also when the buffer is too small, so that
+/// the required capacity can be queried with `capacity` of 0.
+///
+/// # Safety
+///
+/// `color` must be null or valid for reads, `buffer` must be valid for
+/// writes of `capacity` bytes, `length` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn boja_convert(
+    color: *const BojaColor,
+    format: u32,
+    buffer: *mut c_char,
+    capacity: usize,
+    length: *mut usize,
+) -> BojaStatus {
+    boja_convert_with(color, format, ptr::null(), buffer, capacity, length)
+}
+
+/// Same as `boja_convert`, with precision and white point given by
+/// `options`, or the defaults if it is null.
+///
+/// # Safety
+///
+/// `options` must be null or valid for reads, see `boja_convert` for the
+/// other arguments.
+#[no_mangle]
+pub unsafe extern "C" fn boja_convert_with(
+    color: *const BojaColor,
+    format: u32,
+    options: *const BojaOptions,
+    buffer: *mut c_char,
+    capacity: usize,
+    length: *mut usize,
+) -> BojaStatus {
+    if color.is_null() {
+        return BojaStatus::NullPointer;
+    }
+
+    let format = match BojaFormat::try_from(format) {
+        Ok(format) => format,
+        Err(status) => return status,
+    };
+
+    let options = if options.is_null() {
+        Ok(Options::default())
+    } else {
+        Options::try_from(options.read())
+    };
+
+    let options = match options {
+        Ok(options) => options,
+        Err(status) => return status,
+    };
+
+    let output = match Color::from(color.read()).convert_with(format.into(), &options) {
+        Ok(output) => output,
+        Err(_) => return BojaStatus::InvalidOptions,
+    };
+
+    if !length.is_null() {
+        length.write(output.len());
+    }
+
+    if output.len() >= capacity {
+        return BojaStatus::BufferTooSmall;
+    }
+
+    if buffer.is_null() {
+        return BojaStatus::NullPointer;
+    }
+
+    ptr::copy_nonoverlapping(output.as_ptr().cast(), buffer, output.len());
+    buffer.add(output.len()).write(0);
+
+    BojaStatus::Ok
+}
+
+/// Writes channels of `color` clamped to sRGB gamut as 8 bit red, green, blue
+/// and alpha into `rgba`.
+///
+/// # Safety
+///
+/// `color` must be null or valid for reads, `rgba` must be null or valid for
+/// writes of 4 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn boja_to_rgba8(color: *const BojaColor, rgba: *mut u8) -> BojaStatus {
+    if color.is_null() || rgba.is_null() {
+        return BojaStatus::NullPointer;
+    }
+
+    let color = Color::from(color.read()).clamped();
+    let channels = [color.red(), color.green(), color.blue(), color.alpha()]
+        .map(|channel| (channel * 255.0).round() as u8);
+
+    ptr::copy_nonoverlapping(channels.as_ptr(), rgba, channels.len());
+
+    BojaStatus::Ok
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr;
+
+    use super::{
+        boja_convert, boja_convert_with, boja_parse, BojaColor, BojaFormat, BojaOptions,
+        BojaStatus, FORMATS,
+    };
+
+    #[test]
+    fn parse_and_convert() {
+        let mut color = BojaColor {
+            red: 0.0,
+            green: 0.0,
+            blue: 0.0,
+            alpha: 0.0,
+        };
+
+        let status = unsafe { boja_parse(c"#3366cc".as_ptr(), &mut color) };
+        assert_eq!(status, BojaStatus::Ok);
+        assert_eq!(color.alpha, 1.0);
+
+        let mut length = 0;
+        let status = unsafe {
+            boja_convert(
+                &color,
+                BojaFormat::Rgb as u32,
+                ptr::null_mut(),
+                0,
+                &mut length,
+            )
+        };
+        assert_eq!(status, BojaStatus::BufferTooSmall);
+        assert_eq!(length, "rgb(51, 102, 204)".len());
+
+        let mut buffer = vec![0; length + 1];
+        let status = unsafe {
+            boja_convert(
+                &color,
+                BojaFormat::Rgb as u32,
+                buffer.as_mut_ptr(),
+                buffer.len(),
+                ptr::null_mut(),
+            )
+        };
+        assert_eq!(status, BojaStatus::Ok);
+
+        let output = unsafe { std::ffi::CStr::from_ptr(buffer.as_ptr()) };
+        assert_eq!(output.to_str(), Ok("rgb(51, 102, 204)"));
+    }
+
+    #[test]
+    fn errors() {
+        let mut color = BojaColor {
+            red: 0.0,
+            green: 0.0,
+            blue: 0.0,
+            alpha: 0.0,
+        };
+
+        let status = unsafe { boja_parse(c"hwb(220 20 20)".as_ptr(), &mut color) };
+        assert_eq!(status, BojaStatus::InvalidColor);
+
+        let status = unsafe { boja_parse(ptr::null(), &mut color) };
+        assert_eq!(status, BojaStatus::NullPointer);
+
+        let status = unsafe { boja_convert(&color, 30, ptr::null_mut(), 0, ptr::null_mut()) };
+        assert_eq!(status, BojaStatus::InvalidFormat);
+    }
+
+    #[test]
+    fn formats() {
+        let color = BojaColor {
+            red: 0.2,
+            green: 0.4,
+            blue: 0.8,
+            alpha: 1.0,
+        };
+        let mut buffer = [0; 128];
+
+        for (index, format) in FORMATS.into_iter().enumerate() {
+            assert_eq!(format as usize, index);
+            assert_eq!(BojaFormat::try_from(index as u32), Ok(format));
+
+            // Every format is written by the library.
+            let status = unsafe {
+                boja_convert(
+                    &color,
+                    index as u32,
+                    buffer.as_mut_ptr(),
+                    buffer.len(),
+                    ptr::null_mut(),
+                )
+            };
+            assert_eq!(status, BojaStatus::Ok, "{format:?}");
+        }
+
+        assert_eq!(
+            BojaFormat::try_from(FORMATS.len() as u32),
+            Err(BojaStatus::InvalidFormat)
+        );
+    }
+
+    #[test]
+    fn options() {
+        let color = BojaColor {
+            red: 0.2,
+            green: 0.4,
+            blue: 0.8,
+            alpha: 1.0,
+        };
+        let mut options = BojaOptions {
+            precision: 1,
+            white_x: 0.3457,
+            white_y: 0.3585,
+        };
+        let mut buffer = [0; 64];
+
+        let status = unsafe {
+            boja_convert_with(
+                &color,
+                BojaFormat::Hsl as u32,
+                &options,
+                buffer.as_mut_ptr(),
+                buffer.len(),
+                ptr::null_mut(),
+            )
+        };
+        assert_eq!(status, BojaStatus::Ok);
+
+        let output = unsafe { std::ffi::CStr::from_ptr(buffer.as_ptr()) };
+        assert_eq!(output.to_str(), Ok("hsl(220.0, 60.0, 50.0)"));
+
+        options.white_y = 0.0;
+        let status = unsafe {
+            boja_convert_with(
+                &color,
+                BojaFormat::Xyz as u32,
+                &options,
+                buffer.as_mut_ptr(),
+                buffer.len(),
+                ptr::null_mut(),
+            )
+        };
+        assert_eq!(status, BojaStatus::InvalidOptions);
+    }
+}
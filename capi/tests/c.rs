@@ -0,0 +1,26 @@
+use std::{env, path::Path, process::Command};
+
+/// Compiles `tests/c/test.c` against the static library and runs it.
+#[test]
+fn c_program() {
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // Static library is built next to the directory of test executables.
+    let exe = env::current_exe().unwrap();
+    let target_dir = exe.parent().and_then(Path::parent).unwrap();
+    let program = Path::new(env!("CARGO_TARGET_TMPDIR")).join("boja_c_test");
+
+    let compiled = Command::new(env::var("CC").unwrap_or_else(|_| String::from("cc")))
+        .arg(crate_dir.join("tests/c/test.c"))
+        .arg("-I")
+        .arg(crate_dir.join("include"))
+        .arg(target_dir.join("libboja_capi.a"))
+        .args(["-lm", "-lpthread", "-ldl", "-o"])
+        .arg(&program)
+        .status()
+        .expect("C compiler is available");
+    assert!(compiled.success());
+
+    let output = Command::new(&program).output().unwrap();
+    assert!(output.status.success(), "{output:?}");
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "ok\n");
+}
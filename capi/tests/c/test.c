@@ -0,0 +1,46 @@
+#include <assert.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "boja.h"
+
+int main(void) {
+    BojaColor color;
+    char output[64];
+    size_t length;
+
+    assert(boja_parse("#3366cc", &color) == BOJA_STATUS_OK);
+    assert(color.alpha == 1.0);
+
+    assert(boja_convert(&color, BOJA_FORMAT_OKLCH, output, sizeof output, &length) ==
+           BOJA_STATUS_OK);
+    assert(strcmp(output, "oklch(0.5325, 0.1679, 262.2930)") == 0);
+    assert(length == strlen(output));
+
+    assert(boja_convert(&color, BOJA_FORMAT_HSL, NULL, 0, &length) ==
+           BOJA_STATUS_BUFFER_TOO_SMALL);
+    assert(length == strlen("hsl(220, 60, 50)"));
+
+    assert(boja_convert(&color, 1000, output, sizeof output, &length) ==
+           BOJA_STATUS_INVALID_FORMAT);
+
+    BojaOptions options = {.precision = 1, .white_x = 0.3457, .white_y = 0.3585};
+    assert(boja_convert_with(&color, BOJA_FORMAT_HSL, &options, output, sizeof output,
+                             &length) == BOJA_STATUS_OK);
+    assert(strcmp(output, "hsl(220.0, 60.0, 50.0)") == 0);
+
+    options.white_y = -1.0;
+    assert(boja_convert_with(&color, BOJA_FORMAT_XYZ, &options, output, sizeof output,
+                             &length) == BOJA_STATUS_INVALID_OPTIONS);
+
+    uint8_t rgba[4];
+    assert(boja_to_rgba8(&color, rgba) == BOJA_STATUS_OK);
+    assert(rgba[0] == 51 && rgba[1] == 102 && rgba[2] == 204 && rgba[3] == 255);
+
+    assert(boja_parse("hsl(220, 160, 50)", &color) == BOJA_STATUS_INVALID_COLOR);
+    assert(boja_parse("\xff", &color) == BOJA_STATUS_INVALID_UTF8);
+    assert(boja_parse(NULL, &color) == BOJA_STATUS_NULL_POINTER);
+
+    puts("ok");
+    return 0;
+}
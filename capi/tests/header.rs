@@ -0,0 +1,25 @@
+use std::{env, fs, path::Path};
+
+/// Checks that the header matches the C interface. The header is written
+/// instead if `BOJA_UPDATE_HEADER` is set.
+#[test]
+fn header_is_up_to_date() {
+    let crate_dir = env!("CARGO_MANIFEST_DIR");
+    let path = Path::new(crate_dir).join("include/boja.h");
+
+    let mut generated = Vec::new();
+    cbindgen::generate(crate_dir)
+        .expect("header is generated")
+        .write(&mut generated);
+    let generated = String::from_utf8(generated).unwrap();
+
+    if env::var_os("BOJA_UPDATE_HEADER").is_some() {
+        fs::write(&path, &generated).unwrap();
+    }
+
+    assert_eq!(
+        fs::read_to_string(&path).unwrap_or_default(),
+        generated,
+        "include/boja.h is outdated, run tests with BOJA_UPDATE_HEADER=1"
+    );
+}
@@ -0,0 +1,214 @@
+//! Configuration file with user-defined color spaces.
+//!
+//! Configuration is written in TOML, e.g.:
+//!
+//! ```toml
+//! [space.studio-monitor]
+//! red = [0.6835, 0.3060]
+//! green = [0.2650, 0.6680]
+//! blue = [0.1500, 0.0600]
+//! white = [0.3127, 0.3290]
+//! transfer = { gamma = 2.4 }
+//! ```
+//!
+//! White point defaults to D65 and may also be given by name, e.g. `"D50"`. Transfer function is one of `"linear"`,
+//! `"srgb"`, `{ gamma = 2.2 }` or `{ parametric = { g = 2.4, a = 0.948, ... } }`.
+
+use std::{collections::BTreeMap, path::Path};
+
+use anyhow::Context;
+
+use crate::{
+    format::{RgbSpace, Transfer, D65_CHROMATICITY},
+    Adaptation, AlphaPosition, WhitePoint,
+};
+
+/// Configuration used to interpret input colors: color spaces available for
+/// conversion, i.e. spaces predefined by CSS and spaces defined in
+/// configuration file, reference white of XYZ based input and placement of
+/// alpha in hexadecimal input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    spaces: Vec<RgbSpace>,
+    white_point: WhitePoint,
+    adaptation: Adaptation,
+    hex_alpha: AlphaPosition,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    #[serde(default)]
+    space: BTreeMap<String, SpaceDefinition>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SpaceDefinition {
+    red: (f64, f64),
+    green: (f64, f64),
+    blue: (f64, f64),
+    #[serde(default)]
+    white: White,
+    transfer: Transfer,
+}
+
+/// White point given either by name of standard illuminant or by chromaticity.
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum White {
+    Named(String),
+    Chromaticity(f64, f64),
+}
+
+impl Default for White {
+    fn default() -> Self {
+        let (x, y) = D65_CHROMATICITY;
+        White::Chromaticity(x, y)
+    }
+}
+
+impl White {
+    fn chromaticity(&self) -> anyhow::Result<(f64, f64)> {
+        match self {
+            White::Named(name) => Ok(name.parse::<WhitePoint>()?.chromaticity()),
+            White::Chromaticity(x, y) => Ok((*x, *y)),
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            spaces: RgbSpace::predefined(),
+            white_point: WhitePoint::default(),
+            adaptation: Adaptation::default(),
+            hex_alpha: AlphaPosition::default(),
+        }
+    }
+}
+
+impl Config {
+    /// Reads configuration file at given path.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read config file {}", path.display()))?;
+
+        Self::from_toml(&content).with_context(|| format!("Invalid config file {}", path.display()))
+    }
+
+    /// Parses configuration from TOML. Spaces defined in configuration take
+    /// precedence over predefined spaces with the same name.
+    pub fn from_toml(content: &str) -> anyhow::Result<Self> {
+        let file: ConfigFile = toml::from_str(content)?;
+
+        let mut spaces = file
+            .space
+            .into_iter()
+            .map(|(name, space)| {
+                let context = format!("Invalid color space '{name}'");
+
+                RgbSpace::new(
+                    name,
+                    [space.red, space.green, space.blue],
+                    space.white.chromaticity()?,
+                    space.transfer,
+                )
+                .context(context)
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        spaces.extend(RgbSpace::predefined());
+
+        Ok(Self {
+            spaces,
+            ..Self::default()
+        })
+    }
+
+    /// Sets reference white of XYZ based input, e.g. `xyz(...)`.
+    pub fn with_white_point(self, white_point: WhitePoint) -> Self {
+        Self {
+            white_point,
+            ..self
+        }
+    }
+
+    /// Sets transform used to adapt input colors between white points.
+    pub fn with_adaptation(self, adaptation: Adaptation) -> Self {
+        Self { adaptation, ..self }
+    }
+
+    /// Sets placement of alpha in hexadecimal input with alpha, e.g.
+    /// `803366cc` is read as AARRGGBB with [`AlphaPosition::First`].
+    pub fn with_hex_alpha(self, hex_alpha: AlphaPosition) -> Self {
+        Self { hex_alpha, ..self }
+    }
+
+    pub fn white_point(&self) -> WhitePoint {
+        self.white_point
+    }
+
+    pub fn adaptation(&self) -> Adaptation {
+        self.adaptation
+    }
+
+    pub fn hex_alpha(&self) -> AlphaPosition {
+        self.hex_alpha
+    }
+
+    pub fn spaces(&self) -> &[RgbSpace] {
+        &self.spaces
+    }
+
+    /// Returns color space with given name.
+    pub fn space(&self, name: &str) -> Option<&RgbSpace> {
+        self.spaces
+            .iter()
+            .find(|space| space.name().eq_ignore_ascii_case(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Config;
+
+    #[test]
+    fn custom_space() {
+        let config = Config::from_toml(
+            r#"
+            [space.monitor]
+            red = [0.68, 0.32]
+            green = [0.265, 0.69]
+            blue = [0.15, 0.06]
+            transfer = { gamma = 2.2 }
+
+            [space.print]
+            red = [0.64, 0.33]
+            green = [0.21, 0.71]
+            blue = [0.15, 0.06]
+            white = "D50"
+            transfer = { parametric = { g = 2.4, a = 0.9479, b = 0.0521, c = 0.0774, d = 0.04045 } }
+            "#,
+        )
+        .unwrap();
+
+        assert!(config.space("monitor").is_some());
+        assert!(config.space("print").is_some());
+        assert!(config.space("display-p3").is_some());
+    }
+
+    #[test]
+    fn invalid_space() {
+        let missing_transfer =
+            "[space.monitor]\nred = [0.68, 0.32]\ngreen = [0.265, 0.69]\nblue = [0.15, 0.06]";
+        let bad_gamma = format!("{missing_transfer}\ntransfer = {{ gamma = 0 }}");
+        let flat_segment = format!(
+            "{missing_transfer}\ntransfer = {{ parametric = {{ g = 2.4, c = 0, d = 0.04 }} }}"
+        );
+
+        assert!(Config::from_toml(missing_transfer).is_err());
+        assert!(Config::from_toml(&bad_gamma).is_err());
+        assert!(Config::from_toml(&flat_segment).is_err());
+    }
+}
@@ -0,0 +1,100 @@
+//! Errors of color parsing and conversion.
+
+use core::ops::RangeInclusive;
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+#[cfg(feature = "std")]
+mod parse;
+
+#[cfg(feature = "std")]
+pub use parse::*;
+
+/// Error of conversion of values into a color format.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConversionError {
+    /// Value of a component is outside of its valid range.
+    OutOfRange {
+        component: &'static str,
+        range: RangeInclusive<f64>,
+        value: f64,
+    },
+    /// Value of a component is not a valid number.
+    Parse {
+        component: &'static str,
+        value: String,
+    },
+    /// Number of components is not one of the expected ones.
+    WrongCount { expected: Vec<usize>, found: usize },
+    /// No color space with given name is known.
+    UnknownSpace { name: String },
+    /// Colors cannot be converted between given spaces.
+    NoConversion { from: String, to: String },
+    /// Name of a color space contains characters other than letters, digits,
+    /// `-` or `_`.
+    InvalidName { name: String },
+    /// Name of a LaTeX color contains characters other than ASCII letters
+    /// and digits.
+    InvalidColorName { name: String },
+    /// Primaries of an RGB space lie on a line, so they do not span a gamut.
+    CollinearPrimaries,
+}
+
+impl core::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ConversionError::OutOfRange {
+                component,
+                range,
+                value,
+            } => {
+                let (start, end) = (*range.start(), *range.end());
+
+                if end.is_finite() && start == f64::MIN_POSITIVE {
+                    write!(f, "{component} must be greater than 0 and at most {end}")?;
+                } else if end.is_finite() {
+                    write!(f, "{component} must be in range {start}..{end}")?;
+                } else if start == f64::MIN_POSITIVE {
+                    write!(f, "{component} must be greater than 0")?;
+                } else if start == 0.0 {
+                    write!(f, "{component} must not be negative")?;
+                } else {
+                    write!(f, "{component} must be at least {start}")?;
+                }
+
+                write!(f, ", found {value}")
+            }
+            ConversionError::Parse { component, value } => {
+                write!(f, "Invalid value of {component}: {value:?}")
+            }
+            ConversionError::WrongCount { expected, found } => {
+                let expected = expected
+                    .iter()
+                    .map(usize::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" or ");
+                write!(f, "Expected {expected} values, found {found}")
+            }
+            ConversionError::UnknownSpace { name } => write!(f, "Unknown color space '{name}'"),
+            ConversionError::NoConversion { from, to } => {
+                write!(f, "No conversion from '{from}' to '{to}'")
+            }
+            ConversionError::InvalidName { name } => write!(
+                f,
+                "Name of color space must consist of letters, digits, '-' or '_', found '{name}'"
+            ),
+            ConversionError::InvalidColorName { name } => write!(
+                f,
+                "Name of color must consist of ASCII letters and digits, found '{name}'"
+            ),
+            ConversionError::CollinearPrimaries => {
+                write!(f, "Primaries of color space must not lie on a line")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ConversionError {}
@@ -0,0 +1,203 @@
+//! Errors of parsing colors from text.
+
+use std::ops::{Range, RangeInclusive};
+
+use itertools::Itertools;
+
+/// Error produced by the parser, carrying messages used in diagnostics.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct CustomError {
+    pub msg: String,
+    pub span: Range<usize>,
+    pub expected: Vec<String>,
+    pub found: Vec<String>,
+    pub kind: ErrorKind,
+}
+
+/// Cause of [`CustomError`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// Input does not match syntax of any format.
+    #[default]
+    Syntax,
+    /// Function with given name is not known, e.g. `lab(...)`.
+    UnknownFunction(String),
+    /// Color space of CSS `color()` function is not known.
+    UnknownSpace(String),
+    /// Value of a component is out of its range or otherwise invalid.
+    OutOfRange,
+    /// Function was given wrong number of arguments.
+    WrongArity {
+        expected: RangeInclusive<usize>,
+        found: usize,
+    },
+}
+
+/// Error of parsing a color, with span of the input where it occurred.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// Input does not match syntax of any format.
+    Syntax {
+        expected: Vec<String>,
+        found: Option<String>,
+        span: Range<usize>,
+    },
+    /// Function with given name is not known, e.g. `lab(...)`.
+    UnknownFunction { name: String, span: Range<usize> },
+    /// Color space of CSS `color()` function is not known.
+    UnknownSpace { name: String, span: Range<usize> },
+    /// Value of a component is out of its range or otherwise invalid.
+    OutOfRange { message: String, span: Range<usize> },
+    /// Function was given wrong number of arguments.
+    WrongArity {
+        expected: RangeInclusive<usize>,
+        found: usize,
+        span: Range<usize>,
+    },
+}
+
+impl ParseError {
+    /// Returns range of characters of the input where the error occurred.
+    pub fn span(&self) -> Range<usize> {
+        match self {
+            ParseError::Syntax { span, .. }
+            | ParseError::UnknownFunction { span, .. }
+            | ParseError::UnknownSpace { span, .. }
+            | ParseError::OutOfRange { span, .. }
+            | ParseError::WrongArity { span, .. } => span.clone(),
+        }
+    }
+}
+
+impl From<CustomError> for ParseError {
+    fn from(error: CustomError) -> Self {
+        let span = error.span;
+
+        match error.kind {
+            ErrorKind::Syntax => ParseError::Syntax {
+                expected: error.expected,
+                found: Some(error.found.concat()).filter(|found| !found.is_empty()),
+                span,
+            },
+            ErrorKind::UnknownFunction(name) => ParseError::UnknownFunction { name, span },
+            ErrorKind::UnknownSpace(name) => ParseError::UnknownSpace { name, span },
+            ErrorKind::OutOfRange => ParseError::OutOfRange {
+                message: error.found.join(", "),
+                span,
+            },
+            ErrorKind::WrongArity { expected, found } => ParseError::WrongArity {
+                expected,
+                found,
+                span,
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseError::Syntax {
+                expected, found, ..
+            } => {
+                write!(f, "Unexpected ")?;
+
+                match found {
+                    Some(found) => write!(f, "input {found:?}")?,
+                    None => write!(f, "end of input")?,
+                }
+
+                if !expected.is_empty() {
+                    write!(f, ", expected {}", expected.join(", "))?;
+                }
+
+                Ok(())
+            }
+            ParseError::UnknownFunction { name, .. } => write!(f, "Unknown function '{name}'"),
+            ParseError::UnknownSpace { name, .. } => write!(f, "Unknown color space '{name}'"),
+            ParseError::OutOfRange { message, .. } => write!(f, "Invalid value: {message}"),
+            ParseError::WrongArity {
+                expected, found, ..
+            } => {
+                if expected.start() == expected.end() {
+                    write!(f, "Expected {} arguments", expected.start())?;
+                } else {
+                    write!(
+                        f,
+                        "Expected {} to {} arguments",
+                        expected.start(),
+                        expected.end()
+                    )?;
+                }
+
+                write!(f, " but found {found}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl chumsky::Error<char> for CustomError {
+    type Span = Range<usize>;
+
+    type Label = String;
+
+    fn expected_input_found<Iter: IntoIterator<Item = Option<char>>>(
+        span: Self::Span,
+        expected: Iter,
+        found: Option<char>,
+    ) -> Self {
+        Self {
+            msg: String::from("Unexpected input. Expected"),
+            span,
+            expected: expected
+                .into_iter()
+                .filter_map(|opt| opt.map(|ch| ch.to_string()))
+                .collect(),
+            found: found.map(|ch| ch.to_string()).into_iter().collect(),
+            kind: ErrorKind::Syntax,
+        }
+    }
+
+    fn with_label(self, label: Self::Label) -> Self {
+        Self { msg: label, ..self }
+    }
+
+    fn merge(self, other: Self) -> Self {
+        // Prefer the more specific error, e.g. unknown function over
+        // mismatched prefix of another function.
+        let prefer_self = other.kind == ErrorKind::Syntax && self.kind != ErrorKind::Syntax;
+
+        let (msg, kind, span) = if prefer_self {
+            (self.msg, self.kind, self.span)
+        } else {
+            (other.msg, other.kind, self.span.start..other.span.end)
+        };
+
+        Self {
+            msg,
+            span,
+            expected: self
+                .expected
+                .into_iter()
+                .chain(other.expected)
+                .unique()
+                .collect(),
+            found: self.found.into_iter().chain(other.found).unique().collect(),
+            kind,
+        }
+    }
+}
+
+impl std::fmt::Display for CustomError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let expected = self.expected.join(", ");
+        let found = self.found.concat();
+
+        f.write_fmt(format_args!(
+            "{}: {:?} but found {:?}",
+            self.msg, expected, found
+        ))
+    }
+}
@@ -0,0 +1,136 @@
+//! Chromatic adaptation of XYZ colors between white points.
+
+use crate::{Adaptation, WhitePoint};
+
+use super::{
+    matrix::{apply, diagonal, invert, multiply, xy_to_xyz, Matrix},
+    Xyz,
+};
+
+impl WhitePoint {
+    /// Returns CIE 1931 chromaticity coordinates of the white point.
+    pub fn chromaticity(self) -> (f64, f64) {
+        match self {
+            WhitePoint::A => (0.44757, 0.40745),
+            WhitePoint::C => (0.31006, 0.31616),
+            WhitePoint::D50 => (0.3457, 0.3585),
+            WhitePoint::D55 => (0.33242, 0.34743),
+            WhitePoint::D65 => (0.3127, 0.3290),
+            WhitePoint::D75 => (0.29902, 0.31485),
+            WhitePoint::E => (1.0 / 3.0, 1.0 / 3.0),
+            WhitePoint::F2 => (0.37208, 0.37529),
+            WhitePoint::F7 => (0.31292, 0.32933),
+            WhitePoint::F11 => (0.38052, 0.37713),
+            WhitePoint::Custom(x, y) => (x, y),
+        }
+    }
+}
+
+impl Adaptation {
+    /// Returns matrix converting XYZ to cone response domain of the transform.
+    fn cone_response(self) -> Matrix {
+        match self {
+            Adaptation::Bradford => [
+                [0.8951, 0.2664, -0.1614],
+                [-0.7502, 1.7135, 0.0367],
+                [0.0389, -0.0685, 1.0296],
+            ],
+            Adaptation::VonKries => [
+                [0.40024, 0.7076, -0.08081],
+                [-0.2263, 1.16532, 0.0457],
+                [0.0, 0.0, 0.91822],
+            ],
+            Adaptation::Cat02 => [
+                [0.7328, 0.4296, -0.1624],
+                [-0.7036, 1.6975, 0.0061],
+                [0.003, 0.0136, 0.9834],
+            ],
+            Adaptation::Cat16 => [
+                [0.401288, 0.650173, -0.051461],
+                [-0.250268, 1.204414, 0.045854],
+                [-0.002079, 0.048952, 0.953127],
+            ],
+            Adaptation::XyzScaling => diagonal((1.0, 1.0, 1.0)),
+        }
+    }
+
+    /// Returns matrix adapting XYZ colors relative to `source` white point to
+    /// `destination` white point, both given as chromaticity coordinates.
+    pub(crate) fn matrix(self, source: (f64, f64), destination: (f64, f64)) -> Matrix {
+        let cone = self.cone_response();
+        let inverse = invert(&cone).expect("cone response matrix is invertible");
+
+        let (sl, sm, ss) = apply(&cone, xy_to_xyz(source));
+        let (dl, dm, ds) = apply(&cone, xy_to_xyz(destination));
+
+        let scale = diagonal((dl / sl, dm / sm, ds / ss));
+
+        multiply(&inverse, &multiply(&scale, &cone))
+    }
+
+    /// Adapts XYZ color relative to `source` white point to `destination`.
+    pub(crate) fn adapt(self, xyz: Xyz, source: (f64, f64), destination: (f64, f64)) -> Xyz {
+        if source == destination {
+            return xyz;
+        }
+
+        Xyz::from(apply(&self.matrix(source, destination), xyz.into_tuple()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::{format::Xyz, Adaptation, WhitePoint};
+
+    #[test]
+    fn white_is_preserved() {
+        let d50 = WhitePoint::D50.chromaticity();
+        let d65 = WhitePoint::D65.chromaticity();
+
+        for adaptation in [
+            Adaptation::Bradford,
+            Adaptation::VonKries,
+            Adaptation::Cat02,
+            Adaptation::Cat16,
+            Adaptation::XyzScaling,
+        ] {
+            let white = Xyz::from((0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585));
+            let (x, y, z) = adaptation.adapt(white, d50, d65).into_tuple();
+
+            assert!((x - 0.95046).abs() < 1e-4, "{adaptation:?}");
+            assert!((y - 1.0).abs() < 1e-4, "{adaptation:?}");
+            assert!((z - 1.08906).abs() < 1e-4, "{adaptation:?}");
+        }
+    }
+
+    #[test]
+    fn parse_white_point() {
+        assert_eq!("d50".parse::<WhitePoint>(), Ok(WhitePoint::D50));
+        assert_eq!(
+            "0.3457, 0.3585".parse::<WhitePoint>(),
+            Ok(WhitePoint::Custom(0.3457, 0.3585))
+        );
+
+        let error = "0.3,0".parse::<WhitePoint>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "white point y must be greater than 0 and at most 1, found 0"
+        );
+        assert!("daylight".parse::<WhitePoint>().is_err());
+    }
+
+    #[test]
+    fn bradford_reference_value() {
+        // Bradford D65 to D50 matrix as published by Lindbloom.
+        let m = Adaptation::Bradford.matrix(
+            WhitePoint::D65.chromaticity(),
+            WhitePoint::D50.chromaticity(),
+        );
+
+        assert!((m[0][0] - 1.0478).abs() < 1e-3);
+        assert!((m[0][1] - 0.0229).abs() < 1e-3);
+        assert!((m[2][2] - 0.7521).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,155 @@
+//! Generic interface of color spaces.
+//!
+//! Every space is defined relative to a parent space, into which it can be
+//! converted exactly. Parents form a tree rooted in the hub space [`Rgb`],
+//! which is the representation of [`Color`]. Conversion between arbitrary
+//! spaces is done by [`Graph`](super::Graph).
+
+use core::any::{Any, TypeId};
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{error::ConversionError, Color};
+
+use super::Rgb;
+
+/// Description of a single component of a color space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Component {
+    /// Name of the component, e.g. `"hue"`.
+    pub name: &'static str,
+    /// Range of values of the component, or `None` if it is unbounded.
+    pub range: Option<(f64, f64)>,
+    /// Whether the component may be omitted in textual representation.
+    pub optional: bool,
+}
+
+impl Component {
+    pub const fn new(name: &'static str, min: f64, max: f64) -> Self {
+        Self {
+            name,
+            range: Some((min, max)),
+            optional: false,
+        }
+    }
+
+    pub const fn unbounded(name: &'static str) -> Self {
+        Self {
+            name,
+            range: None,
+            optional: false,
+        }
+    }
+
+    /// Creates unbounded component that may be omitted, e.g. peak luminance.
+    pub const fn parameter(name: &'static str) -> Self {
+        Self {
+            name,
+            range: None,
+            optional: true,
+        }
+    }
+}
+
+/// Color space with conversion into its parent space and textual
+/// representation.
+pub trait ColorSpace: Copy + core::fmt::Display + 'static {
+    /// Name of the space, also used as name of its function in textual
+    /// representation, e.g. `"oklch"`.
+    const NAME: &'static str;
+
+    /// Components in the order returned by [`ColorSpace::components`].
+    const COMPONENTS: &'static [Component];
+
+    /// Space this one is converted into exactly. The hub is its own parent.
+    type Parent: ColorSpace;
+
+    fn to_parent(&self) -> Self::Parent;
+
+    fn from_parent(parent: Self::Parent) -> Self;
+
+    /// Returns values of components, described by [`ColorSpace::COMPONENTS`].
+    fn components(&self) -> Vec<f64>;
+
+    /// Creates color from values of components. Missing values are taken as
+    /// zero, except for parameters like peak luminance, which have defaults.
+    fn from_components(components: &[f64]) -> Self;
+
+    /// Parses values given as arguments of the function of the space, e.g.
+    /// `["0.5", "0.1", "120"]` of `oklch(0.5, 0.1, 120)`.
+    fn parse(values: &[String]) -> Result<Self, ConversionError>;
+
+    /// Creates color from values of components, checked like arguments of
+    /// the function of the space. Spaces with components in other units than
+    /// their arguments, e.g. [`Rgb`], check them on their own.
+    fn try_from_components(components: &[f64]) -> Result<Self, ConversionError> {
+        let values: Vec<String> = components.iter().map(ToString::to_string).collect();
+
+        Self::parse(&values)
+    }
+
+    /// Writes color in textual representation with given number of decimal
+    /// places, or with default precision of the space if none is given.
+    fn serialize(&self, precision: Option<usize>) -> String {
+        crate::with_precision(self, precision)
+    }
+
+    /// Converts into the hub space through all parents.
+    fn to_hub(&self) -> Rgb {
+        self.to_parent().to_hub()
+    }
+
+    /// Converts from the hub space through all parents.
+    fn from_hub(rgb: Rgb) -> Self {
+        Self::from_parent(Self::Parent::from_hub(rgb))
+    }
+}
+
+/// Converts `color` into space `T` along parents of both spaces, through the
+/// closest space they are both derived from, e.g. Oklch into Oklab directly
+/// rather than through the hub.
+pub(crate) fn convert<S: ColorSpace, T: ColorSpace>(color: S) -> T {
+    if let Some(converted) = from_ancestor::<S, T>(color) {
+        converted
+    } else if is_hub::<S>() {
+        T::from_hub(color.to_hub())
+    } else {
+        convert::<S::Parent, T>(color.to_parent())
+    }
+}
+
+/// Converts `color` into `T` if space `A` is `T` or one of its ancestors.
+fn from_ancestor<A: ColorSpace, T: ColorSpace>(color: A) -> Option<T> {
+    if let Some(color) = (&color as &dyn Any).downcast_ref::<T>() {
+        Some(*color)
+    } else if is_hub::<T>() {
+        None
+    } else {
+        from_ancestor::<A, T::Parent>(color).map(T::from_parent)
+    }
+}
+
+/// Returns `true` for the root of a tree of spaces, which is its own parent.
+fn is_hub<S: ColorSpace>() -> bool {
+    TypeId::of::<S>() == TypeId::of::<S::Parent>()
+}
+
+/// Returns component at given index, or zero if it is missing.
+pub(crate) fn component(components: &[f64], index: usize) -> f64 {
+    components.get(index).copied().unwrap_or_default()
+}
+
+impl Color {
+    /// Converts color into given space.
+    pub fn to_space<S: ColorSpace>(&self) -> S {
+        S::from_hub(Rgb::from(*self))
+    }
+
+    /// Creates opaque color from color in any space.
+    pub fn from_space<S: ColorSpace>(color: S) -> Self {
+        Color::from(color.to_hub())
+    }
+}
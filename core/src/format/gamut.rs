@@ -0,0 +1,216 @@
+//! Mapping of colors outside of the sRGB gamut into it.
+
+use crate::GamutMapping;
+
+use super::{Oklab, Oklch, Rgb};
+
+/// Just noticeable difference in Oklab, as used by CSS Color Module Level 4.
+const JND: f64 = 0.02;
+
+/// Precision of chroma search.
+const EPSILON: f64 = 0.0001;
+
+impl GamutMapping {
+    /// Maps color into the sRGB gamut. Colors within the gamut are returned
+    /// unchanged.
+    pub(crate) fn map(self, rgb: Rgb) -> Rgb {
+        if in_gamut(rgb) {
+            return rgb;
+        }
+
+        match self {
+            GamutMapping::Css => css(rgb),
+            GamutMapping::Clip => rgb.clamped(),
+            GamutMapping::Scale => scale(rgb),
+            GamutMapping::PreserveLightness => preserve_lightness(rgb),
+        }
+    }
+}
+
+fn in_gamut(rgb: Rgb) -> bool {
+    let (r, g, b) = rgb.into_tuple();
+
+    [r, g, b].iter().all(|c| (0.0..=1.0).contains(c))
+}
+
+/// Returns white or black for colors lighter or darker than the gamut, or
+/// `None` if the lightness is within the gamut.
+fn lightness_limit(oklch: Oklch) -> Option<Rgb> {
+    if oklch.lightness() >= 1.0 {
+        Some(Rgb::from((1.0, 1.0, 1.0)))
+    } else if oklch.lightness() <= 0.0 {
+        Some(Rgb::from((0.0, 0.0, 0.0)))
+    } else {
+        None
+    }
+}
+
+/// Gamut mapping algorithm of CSS Color Module Level 4, reducing chroma in
+/// Oklch until clipped color is indistinguishable from the reduced one.
+fn css(rgb: Rgb) -> Rgb {
+    let origin = Oklch::from(rgb);
+
+    if let Some(limit) = lightness_limit(origin) {
+        return limit;
+    }
+
+    let distance =
+        |clipped: Rgb, current: Oklch| Oklab::from(clipped).distance(&Oklab::from(current));
+
+    let mut clipped = rgb.clamped();
+
+    if distance(clipped, origin) < JND {
+        return clipped;
+    }
+
+    let (mut min, mut max) = (0.0, origin.chroma());
+    let mut min_in_gamut = true;
+
+    while max - min > EPSILON {
+        let chroma = (min + max) / 2.0;
+        let current = origin.with_chroma(chroma);
+        let current_rgb = Rgb::from(Oklab::from(current));
+
+        if min_in_gamut && in_gamut(current_rgb) {
+            min = chroma;
+            continue;
+        }
+
+        clipped = current_rgb.clamped();
+        let error = distance(clipped, current);
+
+        if error < JND {
+            if JND - error < EPSILON {
+                return clipped;
+            }
+
+            min_in_gamut = false;
+            min = chroma;
+        } else {
+            max = chroma;
+        }
+    }
+
+    clipped
+}
+
+/// Reduces saturation in linear light towards gray of the same luminance.
+fn scale(rgb: Rgb) -> Rgb {
+    let (r, g, b) = rgb.to_linear();
+    let luminance = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+
+    if luminance >= 1.0 {
+        return Rgb::from((1.0, 1.0, 1.0));
+    } else if luminance <= 0.0 {
+        return Rgb::from((0.0, 0.0, 0.0));
+    }
+
+    // Largest factor keeping all channels within range 0..1.
+    let factor = [r, g, b]
+        .into_iter()
+        .map(|c| {
+            if c > 1.0 {
+                (1.0 - luminance) / (c - luminance)
+            } else if c < 0.0 {
+                luminance / (luminance - c)
+            } else {
+                1.0
+            }
+        })
+        .fold(1.0, f64::min);
+
+    let scale = |c: f64| luminance + factor * (c - luminance);
+
+    Rgb::from_linear((scale(r), scale(g), scale(b))).clamped()
+}
+
+/// Reduces chroma in Oklch until the color fits into the gamut, keeping its
+/// lightness and hue.
+fn preserve_lightness(rgb: Rgb) -> Rgb {
+    let origin = Oklch::from(rgb);
+
+    if let Some(limit) = lightness_limit(origin) {
+        return limit;
+    }
+
+    let (mut min, mut max) = (0.0, origin.chroma());
+
+    while max - min > EPSILON {
+        let chroma = (min + max) / 2.0;
+
+        if in_gamut(Rgb::from(Oklab::from(origin.with_chroma(chroma)))) {
+            min = chroma;
+        } else {
+            max = chroma;
+        }
+    }
+
+    Rgb::from(Oklab::from(origin.with_chroma(min))).clamped()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        format::{Oklab, Oklch, Rgb},
+        GamutMapping,
+    };
+
+    fn vivid_green() -> Rgb {
+        // Display P3 green, far outside of sRGB.
+        Rgb::from_linear((-0.5116, 1.0183, -0.3107))
+    }
+
+    #[test]
+    fn in_gamut_is_unchanged() {
+        let rgb = Rgb::from((51, 102, 204));
+
+        for mapping in [
+            GamutMapping::Css,
+            GamutMapping::Clip,
+            GamutMapping::Scale,
+            GamutMapping::PreserveLightness,
+        ] {
+            assert_eq!(mapping.map(rgb), rgb);
+        }
+    }
+
+    #[test]
+    fn css_keeps_hue() {
+        let origin = Oklch::from(vivid_green());
+        let mapped = Oklch::from(GamutMapping::Css.map(vivid_green()));
+        let clipped = Oklch::from(GamutMapping::Clip.map(vivid_green()));
+
+        let hue = |oklch: Oklch| Oklab::from(oklch.with_chroma(1.0));
+        let hue_shift = |oklch| hue(origin).distance(&hue(oklch));
+
+        assert!(hue_shift(mapped) < hue_shift(clipped));
+        assert!((mapped.lightness() - origin.lightness()).abs() < 0.02);
+    }
+
+    #[test]
+    fn preserve_lightness() {
+        let origin = Oklch::from(vivid_green());
+        let mapped = Oklch::from(GamutMapping::PreserveLightness.map(vivid_green()));
+
+        assert!((mapped.lightness() - origin.lightness()).abs() < 1e-3);
+    }
+
+    #[test]
+    fn scale_keeps_luminance() {
+        let luminance = |rgb: Rgb| {
+            let (r, g, b) = rgb.to_linear();
+            0.2126 * r + 0.7152 * g + 0.0722 * b
+        };
+
+        let mapped = GamutMapping::Scale.map(vivid_green());
+
+        assert!((luminance(mapped) - luminance(vivid_green())).abs() < 1e-9);
+    }
+
+    #[test]
+    fn too_light() {
+        let rgb = Rgb::from_linear((1.5, 1.2, 1.1));
+
+        assert_eq!(GamutMapping::Css.map(rgb).to_bytes(), (255, 255, 255));
+    }
+}
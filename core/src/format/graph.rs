@@ -0,0 +1,248 @@
+//! Conversion graph routing colors between any two registered spaces.
+
+use std::{collections::VecDeque, sync::OnceLock};
+
+use crate::error::ConversionError;
+
+use super::{
+    color_space::{ColorSpace, Component},
+    registry::{Registration, BUILTIN},
+};
+
+/// Conversion between two adjacent spaces.
+#[derive(Debug, Clone, Copy)]
+struct Step {
+    node: usize,
+    to_parent: bool,
+}
+
+/// Graph of color spaces connected to their parent spaces. Colors are
+/// converted along the shortest path between two spaces, so e.g. Oklch is
+/// converted to Oklab directly rather than through the hub.
+///
+/// Colors are passed around as components, see [`ColorSpace::components`].
+#[derive(Debug, Clone, Default)]
+pub struct Graph {
+    nodes: Vec<Registration>,
+}
+
+impl Graph {
+    /// Returns graph with all spaces of this crate.
+    pub fn builtin() -> &'static Graph {
+        static BUILTIN_GRAPH: OnceLock<Graph> = OnceLock::new();
+
+        BUILTIN_GRAPH.get_or_init(|| Graph {
+            nodes: BUILTIN.to_vec(),
+        })
+    }
+
+    /// Registers space in the graph, replacing space with the same name.
+    pub fn with<S: ColorSpace>(mut self) -> Self {
+        let node = Registration::of::<S>(None);
+
+        match self.index(S::NAME) {
+            Some(index) => self.nodes[index] = node,
+            None => self.nodes.push(node),
+        }
+
+        self
+    }
+
+    /// Returns names of all registered spaces.
+    pub fn spaces(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.nodes.iter().map(|node| node.name)
+    }
+
+    /// Returns components of space with given name.
+    pub fn components(&self, space: &str) -> Option<&'static [Component]> {
+        self.node(space).map(|node| node.components)
+    }
+
+    /// Converts components of color in space `from` into space `to`.
+    pub fn convert(
+        &self,
+        from: &str,
+        to: &str,
+        components: &[f64],
+    ) -> Result<Vec<f64>, ConversionError> {
+        let path = self.path(from, to)?;
+
+        Ok(path.iter().fold(components.to_vec(), |components, step| {
+            let node = &self.nodes[step.node];
+
+            if step.to_parent {
+                (node.to_parent)(&components)
+            } else {
+                (node.from_parent)(&components)
+            }
+        }))
+    }
+
+    /// Parses arguments of the function of given space into its components.
+    pub fn parse(&self, space: &str, values: &[String]) -> Result<Vec<f64>, ConversionError> {
+        (self.existing(space)?.parse)(values)
+    }
+
+    /// Writes components of color in given space in textual representation.
+    pub fn serialize(
+        &self,
+        space: &str,
+        components: &[f64],
+        precision: Option<usize>,
+    ) -> Result<String, ConversionError> {
+        Ok((self.existing(space)?.serialize)(components, precision))
+    }
+
+    fn index(&self, space: &str) -> Option<usize> {
+        self.nodes
+            .iter()
+            .position(|node| node.name.eq_ignore_ascii_case(space))
+    }
+
+    fn node(&self, space: &str) -> Option<&Registration> {
+        self.index(space).map(|index| &self.nodes[index])
+    }
+
+    fn existing(&self, space: &str) -> Result<&Registration, ConversionError> {
+        Ok(&self.nodes[self.existing_index(space)?])
+    }
+
+    fn existing_index(&self, space: &str) -> Result<usize, ConversionError> {
+        self.index(space)
+            .ok_or_else(|| ConversionError::UnknownSpace {
+                name: space.to_string(),
+            })
+    }
+
+    /// Finds the shortest sequence of conversions between two spaces using
+    /// breadth-first search.
+    fn path(&self, from: &str, to: &str) -> Result<Vec<Step>, ConversionError> {
+        let start = self.existing_index(from)?;
+        let end = self.existing_index(to)?;
+
+        // Step leading to every visited node, `None` for the start.
+        let mut previous: Vec<Option<Option<(usize, Step)>>> = vec![None; self.nodes.len()];
+        previous[start] = Some(None);
+
+        let mut queue = VecDeque::from([start]);
+
+        while let Some(current) = queue.pop_front() {
+            if current == end {
+                break;
+            }
+
+            for (next, step) in self.neighbours(current) {
+                if previous[next].is_none() {
+                    previous[next] = Some(Some((current, step)));
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        let mut path = Vec::new();
+        let mut current = end;
+
+        loop {
+            match previous[current] {
+                Some(Some((before, step))) => {
+                    path.push(step);
+                    current = before;
+                }
+                Some(None) => break,
+                None => {
+                    return Err(ConversionError::NoConversion {
+                        from: from.to_string(),
+                        to: to.to_string(),
+                    })
+                }
+            }
+        }
+
+        path.reverse();
+
+        Ok(path)
+    }
+
+    /// Returns spaces adjacent to given one with conversion leading to them.
+    fn neighbours(&self, index: usize) -> Vec<(usize, Step)> {
+        let node = &self.nodes[index];
+        let mut neighbours = Vec::new();
+
+        if let Some(parent) = self.index(node.parent).filter(|&parent| parent != index) {
+            neighbours.push((
+                parent,
+                Step {
+                    node: index,
+                    to_parent: true,
+                },
+            ));
+        }
+
+        for (child, other) in self.nodes.iter().enumerate() {
+            if child != index && self.index(other.parent) == Some(index) {
+                neighbours.push((
+                    child,
+                    Step {
+                        node: child,
+                        to_parent: false,
+                    },
+                ));
+            }
+        }
+
+        neighbours
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::ConversionError,
+        format::{ColorSpace, Oklab, Oklch, Rgb},
+    };
+
+    use super::Graph;
+
+    #[test]
+    fn shortest_path() {
+        let graph = Graph::builtin();
+
+        // Oklch is converted to Oklab directly, not through the hub.
+        let path = graph.path("oklch", "oklab").unwrap();
+        assert_eq!(path.len(), 1);
+
+        let path = graph.path("jzczhz", "xyy").unwrap();
+        assert_eq!(path.len(), 3);
+    }
+
+    #[test]
+    fn convert() {
+        let graph = Graph::builtin();
+        let rgb = Rgb::from((51, 102, 204));
+
+        let oklch = graph.convert("rgb", "oklch", &rgb.components()).unwrap();
+        assert_eq!(
+            graph.serialize("oklch", &oklch, None).unwrap(),
+            Oklch::from(rgb).to_string()
+        );
+
+        let back = graph.convert("oklch", "hsl", &oklch).unwrap();
+        let hsl = graph.serialize("hsl", &back, Some(0)).unwrap();
+        assert_eq!(hsl, "hsl(220, 60, 50)");
+    }
+
+    #[test]
+    fn parse() {
+        let graph = Graph::builtin();
+        let values = ["0.5", "0.1", "-0.1"].map(String::from);
+
+        let oklab = graph.parse("oklab", &values).unwrap();
+        assert_eq!(oklab, Oklab::from((0.5, 0.1, -0.1)).components());
+        assert_eq!(
+            graph.parse("hwb", &values),
+            Err(ConversionError::UnknownSpace {
+                name: "hwb".to_string()
+            })
+        );
+    }
+}
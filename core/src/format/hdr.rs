@@ -0,0 +1,483 @@
+//! HDR formats using ITU-R BT.2100 transfer functions with BT.2020 primaries.
+//!
+//! Linear color channels are scaled so that value of 1 corresponds to the
+//! given peak luminance in nits (cd/m²).
+
+use alloc::{format, string::String, vec, vec::Vec};
+
+use crate::{error::ConversionError, Color};
+
+use super::{
+    check_count, check_range, component, decimal, parse_component, trimmed_decimal, ColorSpace,
+    Component, Rec2020, Rgb, Xyz,
+};
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+
+/// Default peak luminance in nits used by HDR formats.
+pub const DEFAULT_PEAK_NITS: f64 = 1000.0;
+
+/// Color encoded with Perceptual Quantizer (SMPTE ST 2084) transfer function,
+/// e.g. `pq(0.7518, 0.7518, 0.7518)`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Pq {
+    red: f64,
+    green: f64,
+    blue: f64,
+    peak: f64,
+}
+
+/// Color encoded with Hybrid Log-Gamma transfer function, e.g.
+/// `hlg(1.0, 1.0, 1.0)`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Hlg {
+    red: f64,
+    green: f64,
+    blue: f64,
+    peak: f64,
+}
+
+impl Pq {
+    pub fn new(rgb: Rgb, peak: f64) -> Self {
+        let (red, green, blue) = Rec2020::from(Xyz::from(rgb)).into_tuple();
+        let encode = |c: f64| pq_inverse_eotf(c * peak);
+
+        Self {
+            red: encode(red),
+            green: encode(green),
+            blue: encode(blue),
+            peak,
+        }
+    }
+}
+
+impl From<Pq> for Color {
+    fn from(pq: Pq) -> Self {
+        let decode = |c: f64| pq_eotf(c) / pq.peak;
+        let rgb = Rec2020::from((decode(pq.red), decode(pq.green), decode(pq.blue)));
+
+        Color::from(Xyz::from(rgb).to_rgb())
+    }
+}
+
+impl Hlg {
+    pub fn new(rgb: Rgb, peak: f64) -> Self {
+        let display = Rec2020::from(Xyz::from(rgb));
+        let (red, green, blue) = hlg_inverse_ootf(display, peak).into_tuple();
+
+        Self {
+            red: hlg_oetf(red),
+            green: hlg_oetf(green),
+            blue: hlg_oetf(blue),
+            peak,
+        }
+    }
+}
+
+impl From<Hlg> for Color {
+    fn from(hlg: Hlg) -> Self {
+        let scene = Rec2020::from((
+            hlg_inverse_oetf(hlg.red),
+            hlg_inverse_oetf(hlg.green),
+            hlg_inverse_oetf(hlg.blue),
+        ));
+
+        Color::from(Xyz::from(hlg_ootf(scene, hlg.peak)).to_rgb())
+    }
+}
+
+/// Parses encoded channels followed by optional peak luminance in nits.
+fn parse_channels(value: &[String]) -> Result<(f64, f64, f64, f64), ConversionError> {
+    check_count(value, &[3, 4])?;
+
+    let mut channels = [0f64; 3];
+
+    for ((channel, value), name) in channels.iter_mut().zip(value).zip(["red", "green", "blue"]) {
+        *channel = check_range(parse_component(value, name)?, name, 0.0..=1.0)?;
+    }
+
+    let [red, green, blue] = channels;
+
+    Ok((red, green, blue, parse_peak(value.get(3))?))
+}
+
+getters!(Pq {
+    /// Encoded red channel in range 0..1.
+    red,
+    /// Encoded green channel in range 0..1.
+    green,
+    /// Encoded blue channel in range 0..1.
+    blue,
+    /// Peak luminance in nits corresponding to linear channel value of 1.
+    peak,
+});
+
+getters!(Hlg {
+    /// Encoded red channel in range 0..1.
+    red,
+    /// Encoded green channel in range 0..1.
+    green,
+    /// Encoded blue channel in range 0..1.
+    blue,
+    /// Nominal peak luminance of display in nits.
+    peak,
+});
+
+/// Parses optional peak luminance in nits, falling back to the default one.
+pub(crate) fn parse_peak(value: Option<&String>) -> Result<f64, ConversionError> {
+    match value {
+        Some(peak) => check_peak(parse_component(peak, "peak luminance")?),
+        None => Ok(DEFAULT_PEAK_NITS),
+    }
+}
+
+/// Checks that peak luminance in nits is positive and finite.
+pub fn check_peak(peak: f64) -> Result<f64, ConversionError> {
+    let range = f64::MIN_POSITIVE..=f64::INFINITY;
+
+    if peak.is_infinite() {
+        return Err(ConversionError::OutOfRange {
+            component: "peak luminance",
+            range,
+            value: peak,
+        });
+    }
+
+    check_range(peak, "peak luminance", range)
+}
+
+/// Formats peak luminance as the optional last argument, which is omitted
+/// for the default peak luminance.
+pub(crate) fn peak_argument(peak: f64) -> String {
+    if peak == DEFAULT_PEAK_NITS {
+        String::new()
+    } else {
+        format!(", {}", trimmed_decimal(peak, 4))
+    }
+}
+
+impl TryFrom<&[String]> for Pq {
+    type Error = ConversionError;
+
+    fn try_from(value: &[String]) -> Result<Self, Self::Error> {
+        let (red, green, blue, peak) = parse_channels(value)?;
+
+        Ok(Self {
+            red,
+            green,
+            blue,
+            peak,
+        })
+    }
+}
+
+impl TryFrom<&[String]> for Hlg {
+    type Error = ConversionError;
+
+    fn try_from(value: &[String]) -> Result<Self, Self::Error> {
+        let (red, green, blue, peak) = parse_channels(value)?;
+
+        Ok(Self {
+            red,
+            green,
+            blue,
+            peak,
+        })
+    }
+}
+
+impl ColorSpace for Pq {
+    const NAME: &'static str = "pq";
+    const COMPONENTS: &'static [Component] = &[
+        Component::new("red", 0.0, 1.0),
+        Component::new("green", 0.0, 1.0),
+        Component::new("blue", 0.0, 1.0),
+        Component::parameter("peak"),
+    ];
+
+    type Parent = Rgb;
+
+    fn to_parent(&self) -> Rgb {
+        Rgb::from(Color::from(*self))
+    }
+
+    /// Converts color with the default peak luminance.
+    fn from_parent(rgb: Rgb) -> Self {
+        Self::new(rgb, DEFAULT_PEAK_NITS)
+    }
+
+    fn components(&self) -> Vec<f64> {
+        vec![self.red, self.green, self.blue, self.peak]
+    }
+
+    fn from_components(c: &[f64]) -> Self {
+        Self {
+            red: component(c, 0),
+            green: component(c, 1),
+            blue: component(c, 2),
+            peak: c.get(3).copied().unwrap_or(DEFAULT_PEAK_NITS),
+        }
+    }
+
+    fn parse(values: &[String]) -> Result<Self, ConversionError> {
+        Self::try_from(values)
+    }
+}
+
+impl core::fmt::Display for Pq {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let precision = f.precision().unwrap_or(4);
+
+        f.write_fmt(format_args!(
+            "pq({}, {}, {}{})",
+            decimal(self.red, precision),
+            decimal(self.green, precision),
+            decimal(self.blue, precision),
+            peak_argument(self.peak)
+        ))
+    }
+}
+
+impl ColorSpace for Hlg {
+    const NAME: &'static str = "hlg";
+    const COMPONENTS: &'static [Component] = &[
+        Component::new("red", 0.0, 1.0),
+        Component::new("green", 0.0, 1.0),
+        Component::new("blue", 0.0, 1.0),
+        Component::parameter("peak"),
+    ];
+
+    type Parent = Rgb;
+
+    fn to_parent(&self) -> Rgb {
+        Rgb::from(Color::from(*self))
+    }
+
+    /// Converts color with the default peak luminance.
+    fn from_parent(rgb: Rgb) -> Self {
+        Self::new(rgb, DEFAULT_PEAK_NITS)
+    }
+
+    fn components(&self) -> Vec<f64> {
+        vec![self.red, self.green, self.blue, self.peak]
+    }
+
+    fn from_components(c: &[f64]) -> Self {
+        Self {
+            red: component(c, 0),
+            green: component(c, 1),
+            blue: component(c, 2),
+            peak: c.get(3).copied().unwrap_or(DEFAULT_PEAK_NITS),
+        }
+    }
+
+    fn parse(values: &[String]) -> Result<Self, ConversionError> {
+        Self::try_from(values)
+    }
+}
+
+impl core::fmt::Display for Hlg {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let precision = f.precision().unwrap_or(4);
+
+        f.write_fmt(format_args!(
+            "hlg({}, {}, {}{})",
+            decimal(self.red, precision),
+            decimal(self.green, precision),
+            decimal(self.blue, precision),
+            peak_argument(self.peak)
+        ))
+    }
+}
+
+const PQ_M1: f64 = 2610.0 / 16384.0;
+const PQ_M2: f64 = 2523.0 / 4096.0 * 128.0;
+const PQ_C1: f64 = 3424.0 / 4096.0;
+const PQ_C2: f64 = 2413.0 / 4096.0 * 32.0;
+const PQ_C3: f64 = 2392.0 / 4096.0 * 32.0;
+
+/// Maximum luminance representable by PQ, in nits.
+const PQ_MAX_NITS: f64 = 10000.0;
+
+/// Converts display luminance in nits to PQ signal value.
+pub(crate) fn pq_inverse_eotf(nits: f64) -> f64 {
+    let y = (nits / PQ_MAX_NITS).clamp(0.0, 1.0).powf(PQ_M1);
+
+    ((PQ_C1 + PQ_C2 * y) / (1.0 + PQ_C3 * y)).powf(PQ_M2)
+}
+
+/// Converts PQ signal value to display luminance in nits.
+pub(crate) fn pq_eotf(signal: f64) -> f64 {
+    let e = signal.clamp(0.0, 1.0).powf(1.0 / PQ_M2);
+
+    PQ_MAX_NITS * ((e - PQ_C1).max(0.0) / (PQ_C2 - PQ_C3 * e)).powf(1.0 / PQ_M1)
+}
+
+const HLG_A: f64 = 0.17883277;
+const HLG_B: f64 = 1.0 - 4.0 * HLG_A;
+const HLG_C: f64 = 0.55991073;
+
+/// Converts normalized scene linear light to HLG signal value.
+fn hlg_oetf(e: f64) -> f64 {
+    let e = e.clamp(0.0, 1.0);
+
+    if e <= 1.0 / 12.0 {
+        (3.0 * e).sqrt()
+    } else {
+        HLG_A * (12.0 * e - HLG_B).ln() + HLG_C
+    }
+}
+
+/// Converts HLG signal value to normalized scene linear light.
+fn hlg_inverse_oetf(signal: f64) -> f64 {
+    if signal <= 0.5 {
+        signal * signal / 3.0
+    } else {
+        (((signal - HLG_C) / HLG_A).exp() + HLG_B) / 12.0
+    }
+}
+
+/// System gamma of HLG reference display with given peak luminance.
+fn hlg_gamma(peak: f64) -> f64 {
+    1.2 + 0.42 * (peak / 1000.0).log10()
+}
+
+/// Converts normalized scene light to display light relative to display peak.
+fn hlg_ootf(scene: Rec2020, peak: f64) -> Rec2020 {
+    let gain = scene.luminance().max(0.0).powf(hlg_gamma(peak) - 1.0);
+    let (red, green, blue) = scene.into_tuple();
+
+    Rec2020::from((red * gain, green * gain, blue * gain))
+}
+
+/// Converts display light relative to display peak to normalized scene light.
+fn hlg_inverse_ootf(display: Rec2020, peak: f64) -> Rec2020 {
+    let luminance = display.luminance();
+
+    if luminance <= 0.0 {
+        return Rec2020::from((0.0, 0.0, 0.0));
+    }
+
+    let gamma = hlg_gamma(peak);
+    let gain = luminance.powf((1.0 - gamma) / gamma);
+    let (red, green, blue) = display.into_tuple();
+
+    Rec2020::from((red * gain, green * gain, blue * gain))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{
+        string::{String, ToString},
+        vec::Vec,
+    };
+
+    use crate::{
+        format::{ColorSpace, Rgb},
+        Color,
+    };
+
+    use super::{hlg_inverse_oetf, hlg_oetf, pq_eotf, pq_inverse_eotf, Hlg, Pq};
+
+    #[test]
+    fn pq_reference_values() {
+        // Reference values from ITU-R BT.2100 and BT.2408.
+        for (nits, signal) in [
+            (0.0, 0.0),
+            (100.0, 0.5081),
+            (203.0, 0.5807),
+            (1000.0, 0.7518),
+            (10000.0, 1.0),
+        ] {
+            assert!((pq_inverse_eotf(nits) - signal).abs() < 1e-4, "{nits} nits");
+            assert!(
+                (pq_eotf(signal) - nits).abs() < nits * 1e-3 + 1e-6,
+                "{signal}"
+            );
+        }
+    }
+
+    #[test]
+    fn hlg_reference_values() {
+        for (scene, signal) in [(0.0, 0.0), (1.0 / 12.0, 0.5), (1.0, 1.0)] {
+            assert!((hlg_oetf(scene) - signal).abs() < 1e-6, "{scene}");
+            assert!((hlg_inverse_oetf(signal) - scene).abs() < 1e-6, "{signal}");
+        }
+    }
+
+    #[test]
+    fn pq_white() {
+        let white = Rgb::from((255, 255, 255));
+
+        assert_eq!(
+            Pq::new(white, 1000.0).to_string(),
+            "pq(0.7518, 0.7518, 0.7518)"
+        );
+        assert_eq!(
+            Pq::new(white, 203.0).to_string(),
+            "pq(0.5807, 0.5807, 0.5807, 203)"
+        );
+    }
+
+    #[test]
+    fn hlg_reference_white() {
+        // BT.2408 reference white of 203 nits on 1000 nit display is encoded
+        // at 75% HLG signal.
+        let white = Rgb::from((0.203, 0.203, 0.203));
+        let white = Rgb::from_linear(white.into_tuple());
+
+        assert_eq!(
+            Hlg::new(white, 1000.0).to_string(),
+            "hlg(0.7499, 0.7499, 0.7499)"
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        let color = Color::from(Rgb::from((51, 102, 204)));
+
+        for peak in [203.0, 1000.0, 4000.0] {
+            let pq = Color::from(Pq::new(Rgb::from(color), peak));
+
+            assert_eq!(Rgb::from(pq).to_bytes(), (51, 102, 204), "{peak} nits");
+        }
+
+        for peak in [203.0, 1000.0] {
+            let hlg = Color::from(Hlg::new(Rgb::from(color), peak));
+
+            assert_eq!(Rgb::from(hlg).to_bytes(), (51, 102, 204), "{peak} nits");
+        }
+    }
+
+    #[test]
+    fn peak_roundtrip() {
+        let values = |values: &[&str]| values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+
+        for input in [
+            values(&["0.5807", "0.5807", "0.5807"]),
+            values(&["0.5807", "0.5807", "0.5807", "203"]),
+            values(&["0.5", "0.25", "0.75", "4000.5"]),
+        ] {
+            let pq = Pq::parse(&input).unwrap();
+            let pq_output = pq.to_string();
+            let hlg = Hlg::parse(&input).unwrap();
+            let hlg_output = hlg.to_string();
+
+            assert_eq!(Pq::parse(&arguments(&pq_output, "pq(")).unwrap(), pq);
+            assert_eq!(Hlg::parse(&arguments(&hlg_output, "hlg(")).unwrap(), hlg);
+            assert_eq!(pq_output.matches(',').count(), input.len() - 1);
+        }
+    }
+
+    /// Splits arguments of `color` written as `prefix` followed by arguments.
+    fn arguments(color: &str, prefix: &str) -> Vec<String> {
+        color
+            .strip_prefix(prefix)
+            .and_then(|color| color.strip_suffix(')'))
+            .unwrap()
+            .split(", ")
+            .map(ToString::to_string)
+            .collect()
+    }
+}
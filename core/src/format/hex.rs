@@ -0,0 +1,149 @@
+use alloc::{format, string::String, vec};
+
+use crate::{AlphaPosition, Color, HexCase, HexOptions, HexPrefix};
+
+use super::{to_byte, to_word};
+
+/// Hexadecimal representation of color, e.g. `#3366cc`, `0x3366CC80` or `#fff`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Hex {
+    options: HexOptions,
+
+    /// Use 16 bits (4 hex digits) per channel instead of 8, e.g. `#33336666cccc`.
+    deep: bool,
+}
+
+impl Hex {
+    pub fn new(options: HexOptions) -> Self {
+        Self {
+            options,
+            deep: false,
+        }
+    }
+
+    /// Creates hex representation with 16 bits per channel.
+    pub fn deep(options: HexOptions) -> Self {
+        Self {
+            options,
+            deep: true,
+        }
+    }
+
+    pub fn format(&self, color: Color) -> String {
+        let (max, width) = if self.deep {
+            (u16::MAX, 4)
+        } else {
+            (u8::MAX as u16, 2)
+        };
+
+        let [red, green, blue, alpha] =
+            [color.red, color.green, color.blue, color.alpha].map(|c| {
+                if self.deep {
+                    to_word(c)
+                } else {
+                    to_byte(c) as u16
+                }
+            });
+
+        let channels = match (alpha == max, self.options.alpha) {
+            (true, _) => vec![red, green, blue],
+            (false, AlphaPosition::Last) => vec![red, green, blue, alpha],
+            (false, AlphaPosition::First) => vec![alpha, red, green, blue],
+        };
+
+        // Shorthand is lossless only if both digits of every channel are same.
+        let is_short =
+            self.options.short && !self.deep && channels.iter().all(|c| c >> 4 == c & 0xf);
+
+        let digits: String = if is_short {
+            channels.iter().map(|c| format!("{:x}", c & 0xf)).collect()
+        } else {
+            channels.iter().map(|c| format!("{c:0width$x}")).collect()
+        };
+
+        let digits = match self.options.case {
+            HexCase::Lower => digits,
+            HexCase::Upper => digits.to_uppercase(),
+        };
+
+        let prefix = match self.options.prefix {
+            HexPrefix::None => "",
+            HexPrefix::Hash => "#",
+            HexPrefix::ZeroX => "0x",
+        };
+
+        format!("{prefix}{digits}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AlphaPosition, Color, HexCase, HexOptions, HexPrefix};
+
+    use super::Hex;
+
+    #[test]
+    fn default_style() {
+        let hex = Hex::new(HexOptions::default());
+
+        assert_eq!(hex.format(Color::from_rgba(51, 102, 204, 255)), "3366cc");
+    }
+
+    #[test]
+    fn uppercase_with_hash() {
+        let hex = Hex::new(HexOptions {
+            case: HexCase::Upper,
+            prefix: HexPrefix::Hash,
+            ..Default::default()
+        });
+
+        assert_eq!(hex.format(Color::from_rgba(51, 102, 204, 255)), "#3366CC");
+    }
+
+    #[test]
+    fn shorthand() {
+        let hex = Hex::new(HexOptions {
+            prefix: HexPrefix::Hash,
+            short: true,
+            ..Default::default()
+        });
+
+        assert_eq!(hex.format(Color::from_rgba(255, 255, 255, 255)), "#fff");
+        assert_eq!(hex.format(Color::from_rgba(51, 102, 204, 255)), "#36c");
+        assert_eq!(hex.format(Color::from_rgba(51, 102, 204, 136)), "#36c8");
+        assert_eq!(hex.format(Color::from_rgba(51, 102, 205, 255)), "#3366cd");
+    }
+
+    #[test]
+    fn alpha_placement() {
+        let color = Color::from_rgba(51, 102, 204, 128);
+
+        let last = Hex::new(HexOptions::default());
+        let first = Hex::new(HexOptions {
+            alpha: AlphaPosition::First,
+            prefix: HexPrefix::ZeroX,
+            ..Default::default()
+        });
+
+        assert_eq!(last.format(color), "3366cc80");
+        assert_eq!(first.format(color), "0x803366cc");
+    }
+
+    #[test]
+    fn deep() {
+        let hex = Hex::deep(HexOptions {
+            prefix: HexPrefix::Hash,
+            short: true,
+            ..Default::default()
+        });
+
+        assert_eq!(
+            hex.format(Color::from_rgba(51, 102, 204, 255)),
+            "#33336666cccc"
+        );
+        assert_eq!(
+            hex.format(Color::from_unit_rgba(0.5, 0.25, 1.0, 1.0)),
+            "#80004000ffff"
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::{error::ConversionError, Color};
+
+use super::{check_range, component, decimal, parse_component, ColorSpace, Component, Rgb};
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+
+/// HSL color with hue in degrees and saturation and lightness in percent,
+/// e.g. `hsl(220, 60%, 50%)`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Hsl {
+    hue: f64,
+    saturation: f64,
+    lightness: f64,
+}
+
+impl From<Rgb> for Hsl {
+    fn from(rgb: Rgb) -> Self {
+        let (rp, gp, bp) = rgb.into_tuple();
+
+        let c_max = rp.max(gp).max(bp);
+        let c_min = rp.min(gp).min(bp);
+        let delta = c_max - c_min;
+
+        let lightness = (c_max + c_min) / 2.0;
+
+        let hue = if delta == 0f64 {
+            0.0
+        } else {
+            match c_max {
+                max if max == rp => ((gp - bp) / delta) % 6.0 * 60.0,
+                max if max == gp => (2.0 + (bp - rp) / delta) * 60.0,
+                max if max == bp => (4.0 + (rp - gp) / delta) * 60.0,
+                _ => unreachable!("c_max must be equal to red, green or blue"),
+            }
+        };
+
+        let hue = if hue < 0.0 { 360.0 - hue.abs() } else { hue };
+
+        let saturation = if delta == 0f64 {
+            0.0
+        } else {
+            delta / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
+
+        Self {
+            hue,
+            saturation: saturation * 100.0,
+            lightness: lightness * 100.0,
+        }
+    }
+}
+
+impl From<Hsl> for Color {
+    fn from(hsl: Hsl) -> Self {
+        Color::from(Rgb::from(hsl))
+    }
+}
+
+impl From<(f64, f64, f64)> for Hsl {
+    fn from((hue, saturation, lightness): (f64, f64, f64)) -> Self {
+        Self {
+            hue,
+            saturation,
+            lightness,
+        }
+    }
+}
+
+impl TryFrom<&[String]> for Hsl {
+    type Error = ConversionError;
+
+    fn try_from(value: &[String]) -> Result<Self, Self::Error> {
+        let [hue, saturation, lightness] = value else {
+            return Err(ConversionError::WrongCount {
+                expected: vec![3],
+                found: value.len(),
+            });
+        };
+
+        let saturation = parse_component(saturation, "saturation")?;
+        let lightness = parse_component(lightness, "lightness")?;
+
+        Ok(Hsl {
+            hue: parse_component(hue, "hue")?.rem_euclid(360.0),
+            saturation: check_range(saturation, "saturation", 0.0..=100.0)?,
+            lightness: check_range(lightness, "lightness", 0.0..=100.0)?,
+        })
+    }
+}
+
+impl ColorSpace for Hsl {
+    const NAME: &'static str = "hsl";
+    const COMPONENTS: &'static [Component] = &[
+        Component::new("hue", 0.0, 360.0),
+        Component::new("saturation", 0.0, 100.0),
+        Component::new("lightness", 0.0, 100.0),
+    ];
+
+    type Parent = Rgb;
+
+    fn to_parent(&self) -> Rgb {
+        Rgb::from(*self)
+    }
+
+    fn from_parent(rgb: Rgb) -> Self {
+        Self::from(rgb)
+    }
+
+    fn components(&self) -> Vec<f64> {
+        vec![self.hue, self.saturation, self.lightness]
+    }
+
+    fn from_components(c: &[f64]) -> Self {
+        Self::from((component(c, 0), component(c, 1), component(c, 2)))
+    }
+
+    fn parse(values: &[String]) -> Result<Self, ConversionError> {
+        Self::try_from(values)
+    }
+}
+
+impl core::fmt::Display for Hsl {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let precision = f.precision().unwrap_or(0);
+
+        f.write_fmt(format_args!(
+            "hsl({}, {}, {})",
+            decimal(self.hue, precision),
+            decimal(self.saturation, precision),
+            decimal(self.lightness, precision)
+        ))
+    }
+}
+
+impl Hsl {
+    pub fn into_tuple(self) -> (f64, f64, f64) {
+        (self.hue, self.saturation, self.lightness)
+    }
+}
+
+getters!(Hsl {
+    /// Hue in degrees, in range 0..360.
+    hue,
+    /// Saturation in percent.
+    saturation,
+    /// Lightness in percent.
+    lightness,
+});
+
+#[cfg(test)]
+mod tests {
+    use alloc::{
+        format,
+        string::{String, ToString},
+        vec,
+    };
+
+    use crate::{
+        error::ConversionError,
+        format::{hsl::Hsl, Rgb},
+    };
+
+    #[test]
+    fn black_from_rgb() {
+        let black = Rgb::from((0, 0, 0));
+
+        let hsl = Hsl::from(black);
+        assert_eq!(hsl.to_string(), "hsl(0, 0, 0)");
+    }
+
+    #[test]
+    fn white_from_rgb() {
+        let black = Rgb::from((255, 255, 255));
+
+        let hsl = Hsl::from(black);
+        assert_eq!(hsl.to_string(), "hsl(0, 0, 100)");
+    }
+
+    #[test]
+    fn red_from_rgb() {
+        let black = Rgb::from((255, 0, 0));
+
+        let hsl = Hsl::from(black);
+        assert_eq!(hsl.to_string(), "hsl(0, 100, 50)");
+    }
+
+    #[test]
+    fn olive_from_rgb() {
+        let black = Rgb::from((128, 128, 0));
+
+        let hsl = Hsl::from(black);
+        assert_eq!(hsl.to_string(), "hsl(60, 100, 25)");
+    }
+
+    #[test]
+    fn roundtrip_rgb() {
+        let hsl = Hsl::from((200.0, 37.0, 41.0));
+
+        let roundtrip = Hsl::from(Rgb::from(hsl));
+        assert_eq!(
+            format!("{roundtrip:.6}"),
+            "hsl(200.000000, 37.000000, 41.000000)"
+        );
+    }
+
+    #[test]
+    fn conversion_errors() {
+        let values = |values: [&str; 3]| values.map(String::from);
+
+        assert!(matches!(
+            Hsl::try_from(&values(["220", "160", "50"])[..]),
+            Err(ConversionError::OutOfRange {
+                component: "saturation",
+                value,
+                ..
+            }) if value == 160.0
+        ));
+        assert!(matches!(
+            Hsl::try_from(&values(["220", "x", "50"])[..]),
+            Err(ConversionError::Parse {
+                component: "saturation",
+                ..
+            })
+        ));
+        assert_eq!(
+            Hsl::try_from(&values(["220", "60", "50"])[..2]),
+            Err(ConversionError::WrongCount {
+                expected: vec![3],
+                found: 2
+            })
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::{error::ConversionError, Color};
+
+use super::{check_range, component, decimal, parse_component, ColorSpace, Component, Rgb};
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+
+/// HSV color with hue in degrees and saturation and value in percent, e.g.
+/// `hsv(220, 75%, 80%)`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Hsv {
+    hue: f64,
+    saturation: f64,
+    value: f64,
+}
+
+impl From<Rgb> for Hsv {
+    fn from(rgb: Rgb) -> Self {
+        let (rp, gp, bp) = rgb.into_tuple();
+
+        let c_max = rp.max(gp).max(bp);
+        let c_min = rp.min(gp).min(bp);
+        let delta = c_max - c_min;
+
+        let value = c_max;
+
+        let hue = if delta == 0f64 {
+            0.0
+        } else {
+            match c_max {
+                max if max == rp => ((gp - bp) / delta) % 6.0 * 60.0,
+                max if max == gp => (2.0 + (bp - rp) / delta) * 60.0,
+                max if max == bp => (4.0 + (rp - gp) / delta) * 60.0,
+                _ => unreachable!("c_max must be equal to red, green or blue"),
+            }
+        };
+
+        let hue = if hue < 0.0 { 360.0 - hue.abs() } else { hue };
+        let saturation = if delta == 0f64 { 0.0 } else { delta / c_max };
+
+        Self {
+            hue,
+            saturation: saturation * 100.0,
+            value: value * 100.0,
+        }
+    }
+}
+
+impl From<Hsv> for Color {
+    fn from(hsv: Hsv) -> Self {
+        Color::from(Rgb::from(hsv))
+    }
+}
+
+impl From<(f64, f64, f64)> for Hsv {
+    fn from((hue, saturation, value): (f64, f64, f64)) -> Self {
+        Self {
+            hue,
+            saturation,
+            value,
+        }
+    }
+}
+
+impl TryFrom<&[String]> for Hsv {
+    type Error = ConversionError;
+
+    fn try_from(value: &[String]) -> Result<Self, Self::Error> {
+        let [hue, saturation, value] = value else {
+            return Err(ConversionError::WrongCount {
+                expected: vec![3],
+                found: value.len(),
+            });
+        };
+
+        let saturation = parse_component(saturation, "saturation")?;
+        let value = parse_component(value, "value")?;
+
+        Ok(Hsv {
+            hue: parse_component(hue, "hue")?.rem_euclid(360.0),
+            saturation: check_range(saturation, "saturation", 0.0..=100.0)?,
+            value: check_range(value, "value", 0.0..=100.0)?,
+        })
+    }
+}
+
+impl ColorSpace for Hsv {
+    const NAME: &'static str = "hsv";
+    const COMPONENTS: &'static [Component] = &[
+        Component::new("hue", 0.0, 360.0),
+        Component::new("saturation", 0.0, 100.0),
+        Component::new("value", 0.0, 100.0),
+    ];
+
+    type Parent = Rgb;
+
+    fn to_parent(&self) -> Rgb {
+        Rgb::from(*self)
+    }
+
+    fn from_parent(rgb: Rgb) -> Self {
+        Self::from(rgb)
+    }
+
+    fn components(&self) -> Vec<f64> {
+        vec![self.hue, self.saturation, self.value]
+    }
+
+    fn from_components(c: &[f64]) -> Self {
+        Self::from((component(c, 0), component(c, 1), component(c, 2)))
+    }
+
+    fn parse(values: &[String]) -> Result<Self, ConversionError> {
+        Self::try_from(values)
+    }
+}
+
+impl core::fmt::Display for Hsv {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let precision = f.precision().unwrap_or(0);
+
+        f.write_fmt(format_args!(
+            "hsv({}, {}, {})",
+            decimal(self.hue, precision),
+            decimal(self.saturation, precision),
+            decimal(self.value, precision)
+        ))
+    }
+}
+
+impl Hsv {
+    pub fn into_tuple(self) -> (f64, f64, f64) {
+        (self.hue, self.saturation, self.value)
+    }
+}
+
+getters!(Hsv {
+    /// Hue in degrees, in range 0..360.
+    hue,
+    /// Saturation in percent.
+    saturation,
+    /// Value in percent.
+    value,
+});
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString};
+
+    use crate::format::{hsv::Hsv, Rgb};
+
+    #[test]
+    fn black_from_rgb() {
+        let black = Rgb::from((0, 0, 0));
+
+        let hsv = Hsv::from(black);
+        assert_eq!(hsv.to_string(), "hsv(0, 0, 0)");
+    }
+
+    #[test]
+    fn white_from_rgb() {
+        let black = Rgb::from((255, 255, 255));
+
+        let hsv = Hsv::from(black);
+        assert_eq!(hsv.to_string(), "hsv(0, 0, 100)");
+    }
+
+    #[test]
+    fn red_from_rgb() {
+        let black = Rgb::from((255, 0, 0));
+
+        let hsv = Hsv::from(black);
+        assert_eq!(hsv.to_string(), "hsv(0, 100, 100)");
+    }
+
+    #[test]
+    fn olive_from_rgb() {
+        let black = Rgb::from((128, 128, 0));
+
+        let hsv = Hsv::from(black);
+        assert_eq!(hsv.to_string(), "hsv(60, 100, 50)");
+    }
+
+    #[test]
+    fn roundtrip_rgb() {
+        let hsv = Hsv::from((200.0, 37.0, 41.0));
+
+        let roundtrip = Hsv::from(Rgb::from(hsv));
+        assert_eq!(
+            format!("{roundtrip:.6}"),
+            "hsv(200.000000, 37.000000, 41.000000)"
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::{error::ConversionError, Color};
+
+use super::{
+    check_count, check_range, component, decimal, parse_component, parse_peak, peak_argument,
+    pq_eotf, pq_inverse_eotf, ColorSpace, Component, Rec2020, Rgb, Xyz, DEFAULT_PEAK_NITS,
+};
+
+/// ICtCp color as defined by ITU-R BT.2100 for PQ encoding, e.g.
+/// `ictcp(0.7518, 0.0000, 0.0000)`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Ictcp {
+    intensity: f64,
+    tritan: f64,
+    protan: f64,
+    peak: f64,
+}
+
+impl Ictcp {
+    /// Converts color with linear channel value of 1 mapped to `peak` nits.
+    pub fn new(rgb: Rgb, peak: f64) -> Self {
+        Self::from_rec2020(Rec2020::from(Xyz::from(rgb)), peak)
+    }
+
+    fn from_rec2020(rgb: Rec2020, peak: f64) -> Self {
+        let (r, g, b) = rgb.into_tuple();
+        let (r, g, b) = (r * peak, g * peak, b * peak);
+
+        let l = pq_inverse_eotf((1688.0 * r + 2146.0 * g + 262.0 * b) / 4096.0);
+        let m = pq_inverse_eotf((683.0 * r + 2951.0 * g + 462.0 * b) / 4096.0);
+        let s = pq_inverse_eotf((99.0 * r + 309.0 * g + 3688.0 * b) / 4096.0);
+
+        Self {
+            intensity: 0.5 * l + 0.5 * m,
+            tritan: (6610.0 * l - 13613.0 * m + 7003.0 * s) / 4096.0,
+            protan: (17933.0 * l - 17390.0 * m - 543.0 * s) / 4096.0,
+            peak,
+        }
+    }
+}
+
+impl From<Ictcp> for Rec2020 {
+    fn from(ictcp: Ictcp) -> Self {
+        let Ictcp {
+            intensity: i,
+            tritan: ct,
+            protan: cp,
+            peak,
+        } = ictcp;
+
+        let l = pq_eotf(i + 0.008609037038 * ct + 0.111029625003 * cp);
+        let m = pq_eotf(i - 0.008609037038 * ct - 0.111029625003 * cp);
+        let s = pq_eotf(i + 0.560031335711 * ct - 0.320627174987 * cp);
+
+        let r = 3.436606694333 * l - 2.506452118656 * m + 0.069845424323 * s;
+        let g = -0.791329555599 * l + 1.983600451792 * m - 0.192270896193 * s;
+        let b = -0.025949899691 * l - 0.098913714712 * m + 1.124863614402 * s;
+
+        Rec2020::from((r / peak, g / peak, b / peak))
+    }
+}
+
+impl From<Ictcp> for Color {
+    fn from(ictcp: Ictcp) -> Self {
+        Color::from(Xyz::from(Rec2020::from(ictcp)).to_rgb())
+    }
+}
+
+getters!(Ictcp {
+    /// Intensity I in range 0..1.
+    intensity,
+    /// Blue-yellow chroma component Ct.
+    tritan,
+    /// Red-green chroma component Cp.
+    protan,
+    /// Peak luminance in nits corresponding to linear channel value of 1.
+    peak,
+});
+
+impl TryFrom<&[String]> for Ictcp {
+    type Error = ConversionError;
+
+    fn try_from(value: &[String]) -> Result<Self, Self::Error> {
+        check_count(value, &[3, 4])?;
+
+        let intensity = parse_component(&value[0], "I")?;
+        let tritan = parse_component(&value[1], "Ct")?;
+        let protan = parse_component(&value[2], "Cp")?;
+
+        Ok(Self {
+            intensity: check_range(intensity, "I", 0.0..=1.0)?,
+            tritan: check_range(tritan, "Ct", -0.5..=0.5)?,
+            protan: check_range(protan, "Cp", -0.5..=0.5)?,
+            peak: parse_peak(value.get(3))?,
+        })
+    }
+}
+
+impl ColorSpace for Ictcp {
+    const NAME: &'static str = "ictcp";
+    const COMPONENTS: &'static [Component] = &[
+        Component::new("intensity", 0.0, 1.0),
+        Component::new("tritan", -0.5, 0.5),
+        Component::new("protan", -0.5, 0.5),
+        Component::parameter("peak"),
+    ];
+
+    type Parent = Rgb;
+
+    fn to_parent(&self) -> Rgb {
+        Rgb::from(Color::from(*self))
+    }
+
+    /// Converts color with the default peak luminance.
+    fn from_parent(rgb: Rgb) -> Self {
+        Self::new(rgb, DEFAULT_PEAK_NITS)
+    }
+
+    fn components(&self) -> Vec<f64> {
+        vec![self.intensity, self.tritan, self.protan, self.peak]
+    }
+
+    fn from_components(c: &[f64]) -> Self {
+        Self {
+            intensity: component(c, 0),
+            tritan: component(c, 1),
+            protan: component(c, 2),
+            peak: c.get(3).copied().unwrap_or(DEFAULT_PEAK_NITS),
+        }
+    }
+
+    fn parse(values: &[String]) -> Result<Self, ConversionError> {
+        Self::try_from(values)
+    }
+}
+
+impl core::fmt::Display for Ictcp {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let precision = f.precision().unwrap_or(4);
+
+        f.write_fmt(format_args!(
+            "ictcp({}, {}, {}{})",
+            decimal(self.intensity, precision),
+            decimal(self.tritan, precision),
+            decimal(self.protan, precision),
+            peak_argument(self.peak)
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString};
+
+    use crate::{
+        format::{ictcp::Ictcp, Rec2020, Rgb},
+        Color,
+    };
+
+    #[test]
+    fn white() {
+        // Achromatic colors have zero chroma and intensity equal to PQ signal
+        // of their luminance.
+        let white = Rgb::from((255, 255, 255));
+
+        assert_eq!(
+            Ictcp::new(white, 1000.0).to_string(),
+            "ictcp(0.7518, 0.0000, 0.0000)"
+        );
+        assert_eq!(
+            Ictcp::new(white, 100.0).to_string(),
+            "ictcp(0.5081, 0.0000, 0.0000, 100)"
+        );
+    }
+
+    #[test]
+    fn reference_value() {
+        // Linear BT.2020 RGB given in cd/m², as in colour-science library.
+        let rgb = Rec2020::from((0.45620519, 0.03081071, 0.04091952));
+        let ictcp = Ictcp::from_rec2020(rgb, 1.0);
+
+        assert_eq!(format!("{ictcp:.5}"), "ictcp(0.07351, 0.00475, 0.09352, 1)");
+    }
+
+    #[test]
+    fn roundtrip() {
+        let color = Color::from(Rgb::from((51, 102, 204)));
+        let ictcp = Ictcp::new(Rgb::from(color), 203.0);
+
+        assert_eq!(Rgb::from(Color::from(ictcp)).to_bytes(), (51, 102, 204));
+    }
+}
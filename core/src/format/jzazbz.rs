@@ -0,0 +1,385 @@
+//! Jzazbz perceptually uniform color space for HDR, as defined by Safdar et
+//! al. (2017), and its cylindrical form JzCzhz.
+//!
+//! Both are computed from absolute CIE XYZ, where white with linear channel
+//! value of 1 has luminance of given peak in nits.
+
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::{error::ConversionError, Color};
+
+use super::{
+    check_count, check_range, component, decimal, parse_component, parse_peak, peak_argument,
+    ColorSpace, Component, Rgb, Xyz, DEFAULT_PEAK_NITS,
+};
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+
+const B: f64 = 1.15;
+const G: f64 = 0.66;
+const D: f64 = -0.56;
+const D0: f64 = 1.6295499532821566e-11;
+
+/// Jzazbz color, e.g. `jzazbz(0.01758, 0.00000, 0.00000)`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Jzazbz {
+    lightness: f64,
+    a: f64,
+    b: f64,
+    peak: f64,
+}
+
+/// JzCzhz color, i.e. Jzazbz in polar coordinates with hue in degrees, e.g.
+/// `jzczhz(0.01758, 0.00000, 0.00000)`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Jzczhz {
+    lightness: f64,
+    chroma: f64,
+    hue: f64,
+    peak: f64,
+}
+
+impl Jzazbz {
+    /// Converts color with linear channel value of 1 mapped to `peak` nits.
+    pub fn new(rgb: Rgb, peak: f64) -> Self {
+        let (x, y, z) = Xyz::from(rgb).into_tuple();
+
+        Self::from_absolute_xyz((x * peak, y * peak, z * peak), peak)
+    }
+
+    fn from_absolute_xyz((x, y, z): (f64, f64, f64), peak: f64) -> Self {
+        let xp = B * x - (B - 1.0) * z;
+        let yp = G * y - (G - 1.0) * x;
+
+        let l = pq_inverse_eotf(0.41478972 * xp + 0.579999 * yp + 0.014648 * z);
+        let m = pq_inverse_eotf(-0.20151 * xp + 1.120649 * yp + 0.0531008 * z);
+        let s = pq_inverse_eotf(-0.0166008 * xp + 0.2648 * yp + 0.6684799 * z);
+
+        let iz = 0.5 * l + 0.5 * m;
+
+        Self {
+            lightness: (1.0 + D) * iz / (1.0 + D * iz) - D0,
+            a: 3.524 * l - 4.066708 * m + 0.542708 * s,
+            b: 0.199076 * l + 1.096799 * m - 1.295875 * s,
+            peak,
+        }
+    }
+
+    fn to_absolute_xyz(self) -> (f64, f64, f64) {
+        let Jzazbz {
+            lightness: jz,
+            a,
+            b,
+            ..
+        } = self;
+
+        let jz = jz + D0;
+        let iz = jz / (1.0 + D - D * jz);
+
+        let l = pq_eotf(iz + 0.138605043272 * a + 0.058047316156 * b);
+        let m = pq_eotf(iz - 0.138605043272 * a - 0.058047316156 * b);
+        let s = pq_eotf(iz - 0.096019242026 * a - 0.811891896056 * b);
+
+        let xp = 1.924226435788 * l - 1.004792312595 * m + 0.037651404031 * s;
+        let yp = 0.350316762095 * l + 0.726481193932 * m - 0.065384422948 * s;
+        let z = -0.090982810983 * l - 0.312728290523 * m + 1.522766561305 * s;
+
+        let x = (xp + (B - 1.0) * z) / B;
+        let y = (yp + (G - 1.0) * x) / G;
+
+        (x, y, z)
+    }
+}
+
+impl From<Jzazbz> for Color {
+    fn from(jzazbz: Jzazbz) -> Self {
+        let (x, y, z) = jzazbz.to_absolute_xyz();
+        let peak = jzazbz.peak;
+
+        Color::from(Xyz::from((x / peak, y / peak, z / peak)).to_rgb())
+    }
+}
+
+impl From<Jzazbz> for Jzczhz {
+    fn from(jzazbz: Jzazbz) -> Self {
+        Self {
+            lightness: jzazbz.lightness,
+            chroma: jzazbz.a.hypot(jzazbz.b),
+            hue: jzazbz.b.atan2(jzazbz.a).to_degrees().rem_euclid(360.0),
+            peak: jzazbz.peak,
+        }
+    }
+}
+
+impl From<Jzczhz> for Jzazbz {
+    fn from(jzczhz: Jzczhz) -> Self {
+        let (sin, cos) = jzczhz.hue.to_radians().sin_cos();
+
+        Self {
+            lightness: jzczhz.lightness,
+            a: jzczhz.chroma * cos,
+            b: jzczhz.chroma * sin,
+            peak: jzczhz.peak,
+        }
+    }
+}
+
+impl From<Jzczhz> for Color {
+    fn from(jzczhz: Jzczhz) -> Self {
+        Color::from(Jzazbz::from(jzczhz))
+    }
+}
+
+getters!(Jzazbz {
+    /// Lightness Jz.
+    lightness,
+    /// Red-green component az.
+    a,
+    /// Yellow-blue component bz.
+    b,
+    /// Peak luminance in nits corresponding to linear channel value of 1.
+    peak,
+});
+
+getters!(Jzczhz {
+    /// Lightness Jz.
+    lightness,
+    /// Chroma Cz.
+    chroma,
+    /// Hue hz in degrees, in range 0..360.
+    hue,
+    /// Peak luminance in nits corresponding to linear channel value of 1.
+    peak,
+});
+
+impl TryFrom<&[String]> for Jzazbz {
+    type Error = ConversionError;
+
+    fn try_from(value: &[String]) -> Result<Self, Self::Error> {
+        check_count(value, &[3, 4])?;
+
+        let lightness = parse_component(&value[0], "Jz")?;
+        let a = parse_component(&value[1], "az")?;
+        let b = parse_component(&value[2], "bz")?;
+
+        Ok(Self {
+            lightness: check_range(lightness, "Jz", 0.0..=1.0)?,
+            a: check_range(a, "az", -0.5..=0.5)?,
+            b: check_range(b, "bz", -0.5..=0.5)?,
+            peak: parse_peak(value.get(3))?,
+        })
+    }
+}
+
+impl TryFrom<&[String]> for Jzczhz {
+    type Error = ConversionError;
+
+    fn try_from(value: &[String]) -> Result<Self, Self::Error> {
+        check_count(value, &[3, 4])?;
+
+        let lightness = parse_component(&value[0], "Jz")?;
+        let chroma = parse_component(&value[1], "Cz")?;
+
+        Ok(Self {
+            lightness: check_range(lightness, "Jz", 0.0..=1.0)?,
+            chroma: check_range(chroma, "Cz", 0.0..=0.5)?,
+            hue: parse_component(&value[2], "hz")?.rem_euclid(360.0),
+            peak: parse_peak(value.get(3))?,
+        })
+    }
+}
+
+impl ColorSpace for Jzazbz {
+    const NAME: &'static str = "jzazbz";
+    const COMPONENTS: &'static [Component] = &[
+        Component::new("lightness", 0.0, 1.0),
+        Component::new("a", -0.5, 0.5),
+        Component::new("b", -0.5, 0.5),
+        Component::parameter("peak"),
+    ];
+
+    type Parent = Xyz;
+
+    fn to_parent(&self) -> Xyz {
+        let (x, y, z) = self.to_absolute_xyz();
+
+        Xyz::from((x / self.peak, y / self.peak, z / self.peak))
+    }
+
+    /// Converts color with the default peak luminance.
+    fn from_parent(xyz: Xyz) -> Self {
+        let (x, y, z) = xyz.into_tuple();
+        let peak = DEFAULT_PEAK_NITS;
+
+        Self::from_absolute_xyz((x * peak, y * peak, z * peak), peak)
+    }
+
+    fn components(&self) -> Vec<f64> {
+        vec![self.lightness, self.a, self.b, self.peak]
+    }
+
+    fn from_components(c: &[f64]) -> Self {
+        Self {
+            lightness: component(c, 0),
+            a: component(c, 1),
+            b: component(c, 2),
+            peak: c.get(3).copied().unwrap_or(DEFAULT_PEAK_NITS),
+        }
+    }
+
+    fn parse(values: &[String]) -> Result<Self, ConversionError> {
+        Self::try_from(values)
+    }
+}
+
+impl ColorSpace for Jzczhz {
+    const NAME: &'static str = "jzczhz";
+    const COMPONENTS: &'static [Component] = &[
+        Component::new("lightness", 0.0, 1.0),
+        Component::new("chroma", 0.0, 0.5),
+        Component::new("hue", 0.0, 360.0),
+        Component::parameter("peak"),
+    ];
+
+    type Parent = Jzazbz;
+
+    fn to_parent(&self) -> Jzazbz {
+        Jzazbz::from(*self)
+    }
+
+    fn from_parent(jzazbz: Jzazbz) -> Self {
+        Self::from(jzazbz)
+    }
+
+    fn components(&self) -> Vec<f64> {
+        vec![self.lightness, self.chroma, self.hue, self.peak]
+    }
+
+    fn from_components(c: &[f64]) -> Self {
+        Self {
+            lightness: component(c, 0),
+            chroma: component(c, 1),
+            hue: component(c, 2).rem_euclid(360.0),
+            peak: c.get(3).copied().unwrap_or(DEFAULT_PEAK_NITS),
+        }
+    }
+
+    fn parse(values: &[String]) -> Result<Self, ConversionError> {
+        Self::try_from(values)
+    }
+}
+
+impl core::fmt::Display for Jzazbz {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let precision = f.precision().unwrap_or(5);
+
+        f.write_fmt(format_args!(
+            "jzazbz({}, {}, {}{})",
+            decimal(self.lightness, precision),
+            decimal(self.a, precision),
+            decimal(self.b, precision),
+            peak_argument(self.peak)
+        ))
+    }
+}
+
+impl core::fmt::Display for Jzczhz {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let precision = f.precision().unwrap_or(5);
+
+        f.write_fmt(format_args!(
+            "jzczhz({}, {}, {}{})",
+            decimal(self.lightness, precision),
+            decimal(self.chroma, precision),
+            decimal(self.hue, precision),
+            peak_argument(self.peak)
+        ))
+    }
+}
+
+/// Perceptual quantizer with exponent modified for Jzazbz, from absolute
+/// luminance in nits to signal in range 0..1.
+fn pq_inverse_eotf(nits: f64) -> f64 {
+    let y = (nits / 10000.0).max(0.0).powf(N);
+
+    ((C1 + C2 * y) / (1.0 + C3 * y)).powf(P)
+}
+
+/// Inverse of [`pq_inverse_eotf`].
+fn pq_eotf(signal: f64) -> f64 {
+    let e = signal.max(0.0).powf(1.0 / P);
+
+    10000.0 * ((e - C1).max(0.0) / (C2 - C3 * e)).powf(1.0 / N)
+}
+
+const N: f64 = 2610.0 / 16384.0;
+const P: f64 = 1.7 * 2523.0 / 32.0;
+const C1: f64 = 3424.0 / 4096.0;
+const C2: f64 = 2413.0 / 128.0;
+const C3: f64 = 2392.0 / 128.0;
+
+#[cfg(test)]
+mod tests {
+    use alloc::{
+        format,
+        string::{String, ToString},
+        vec::Vec,
+    };
+
+    use crate::{
+        format::{
+            jzazbz::{Jzazbz, Jzczhz},
+            ColorSpace, Rgb,
+        },
+        Color,
+    };
+
+    #[test]
+    fn reference_value() {
+        // Absolute XYZ in cd/m², as in colour-science library.
+        let jzazbz = Jzazbz::from_absolute_xyz((0.20654008, 0.12197225, 0.05136952), 1.0);
+
+        assert_eq!(
+            format!("{jzazbz:.7}"),
+            "jzazbz(0.0053505, 0.0092430, 0.0052601, 1)"
+        );
+    }
+
+    #[test]
+    fn white() {
+        let white = Jzazbz::new(Rgb::from((255, 255, 255)), 1000.0);
+        let jzczhz = Jzczhz::from(white);
+
+        assert_eq!(format!("{jzczhz:.3}").split(", ").nth(1), Some("0.000"));
+    }
+
+    #[test]
+    fn roundtrip() {
+        let color = Color::from(Rgb::from((51, 102, 204)));
+        let jzczhz = Jzczhz::from(Jzazbz::new(Rgb::from(color), 203.0));
+
+        assert_eq!(Rgb::from(Color::from(jzczhz)).to_bytes(), (51, 102, 204));
+    }
+
+    #[test]
+    fn peak_roundtrip() {
+        let rgb = Rgb::from((51, 102, 204));
+        let jzazbz = Jzazbz::new(rgb, 203.0);
+
+        let arguments = |output: &str| {
+            assert!(output.ends_with(", 203)"), "{output}");
+
+            let (_, arguments) = output.trim_end_matches(')').split_once('(').unwrap();
+            arguments.split(", ").map(String::from).collect::<Vec<_>>()
+        };
+
+        let output = jzazbz.to_string();
+        let color = Color::from(Jzazbz::parse(&arguments(&output)).unwrap());
+        assert_eq!(Rgb::from(color).to_bytes(), (51, 102, 204));
+
+        let output = Jzczhz::from(jzazbz).to_string();
+        let color = Color::from(Jzczhz::parse(&arguments(&output)).unwrap());
+        assert_eq!(Rgb::from(color).to_bytes(), (51, 102, 204));
+    }
+}
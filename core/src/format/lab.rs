@@ -0,0 +1,341 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::{error::ConversionError, Adaptation, Color, WhitePoint};
+
+use super::{
+    check_range, component, decimal, matrix::xy_to_xyz, parse_component, ColorSpace, Component,
+    Rgb, Xyz, D65_CHROMATICITY,
+};
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+
+/// Constants of CIE Lab, as exact fractions used by CSS.
+const EPSILON: f64 = 216.0 / 24389.0;
+const KAPPA: f64 = 24389.0 / 27.0;
+
+/// CIE Lab color relative to D50, adapted from D65 with the Bradford
+/// transform as in CSS, e.g. `lab(44.36, 13.88, -57.64)`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Lab {
+    lightness: f64,
+    a: f64,
+    b: f64,
+}
+
+/// CIE LCh color, i.e. Lab in polar coordinates with hue in degrees, e.g.
+/// `lch(44.36, 59.29, 283.54)`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Lch {
+    lightness: f64,
+    chroma: f64,
+    hue: f64,
+}
+
+impl From<Xyz> for Lab {
+    fn from(xyz: Xyz) -> Self {
+        let d50 = WhitePoint::D50.chromaticity();
+        let (x, y, z) = Adaptation::Bradford
+            .adapt(xyz, D65_CHROMATICITY, d50)
+            .into_tuple();
+        let (xn, yn, zn) = xy_to_xyz(d50);
+
+        let f = |t: f64| {
+            if t > EPSILON {
+                t.cbrt()
+            } else {
+                (KAPPA * t + 16.0) / 116.0
+            }
+        };
+        let (fx, fy, fz) = (f(x / xn), f(y / yn), f(z / zn));
+
+        Self {
+            lightness: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+}
+
+impl From<Lab> for Xyz {
+    fn from(lab: Lab) -> Self {
+        let Lab { lightness, a, b } = lab;
+        let d50 = WhitePoint::D50.chromaticity();
+        let (xn, yn, zn) = xy_to_xyz(d50);
+
+        let fy = (lightness + 16.0) / 116.0;
+        let fx = fy + a / 500.0;
+        let fz = fy - b / 200.0;
+
+        let f_inverse = |f: f64| {
+            if f.powi(3) > EPSILON {
+                f.powi(3)
+            } else {
+                (116.0 * f - 16.0) / KAPPA
+            }
+        };
+        let y = if lightness > KAPPA * EPSILON {
+            fy.powi(3)
+        } else {
+            lightness / KAPPA
+        };
+
+        let xyz = Xyz::from((f_inverse(fx) * xn, y * yn, f_inverse(fz) * zn));
+
+        Adaptation::Bradford.adapt(xyz, d50, D65_CHROMATICITY)
+    }
+}
+
+impl From<Rgb> for Lab {
+    fn from(rgb: Rgb) -> Self {
+        Lab::from(Xyz::from(rgb))
+    }
+}
+
+impl From<Lab> for Rgb {
+    /// Converts to sRGB. Colors outside of the sRGB gamut have channels outside
+    /// of range 0..1.
+    fn from(lab: Lab) -> Self {
+        Xyz::from(lab).to_rgb()
+    }
+}
+
+impl From<Lab> for Color {
+    fn from(lab: Lab) -> Self {
+        Color::from(Rgb::from(lab))
+    }
+}
+
+impl From<Lab> for Lch {
+    fn from(lab: Lab) -> Self {
+        Self {
+            lightness: lab.lightness,
+            chroma: lab.a.hypot(lab.b),
+            hue: lab.b.atan2(lab.a).to_degrees().rem_euclid(360.0),
+        }
+    }
+}
+
+impl From<Lch> for Lab {
+    fn from(lch: Lch) -> Self {
+        let (sin, cos) = lch.hue.to_radians().sin_cos();
+
+        Self {
+            lightness: lch.lightness,
+            a: lch.chroma * cos,
+            b: lch.chroma * sin,
+        }
+    }
+}
+
+impl From<Rgb> for Lch {
+    fn from(rgb: Rgb) -> Self {
+        Lch::from(Lab::from(rgb))
+    }
+}
+
+impl From<Lch> for Color {
+    fn from(lch: Lch) -> Self {
+        Color::from(Lab::from(lch))
+    }
+}
+
+impl From<(f64, f64, f64)> for Lab {
+    fn from((lightness, a, b): (f64, f64, f64)) -> Self {
+        Self { lightness, a, b }
+    }
+}
+
+impl From<(f64, f64, f64)> for Lch {
+    fn from((lightness, chroma, hue): (f64, f64, f64)) -> Self {
+        Self {
+            lightness,
+            chroma,
+            hue: hue.rem_euclid(360.0),
+        }
+    }
+}
+
+getters!(Lab {
+    /// Lightness in range 0..100.
+    lightness,
+    /// Green-red component.
+    a,
+    /// Blue-yellow component.
+    b,
+});
+
+getters!(Lch {
+    /// Lightness in range 0..100.
+    lightness,
+    /// Chroma, 0 for achromatic colors.
+    chroma,
+    /// Hue in degrees, in range 0..360.
+    hue,
+});
+
+impl TryFrom<&[String]> for Lab {
+    type Error = ConversionError;
+
+    fn try_from(value: &[String]) -> Result<Self, Self::Error> {
+        let [lightness, a, b] = value else {
+            return Err(ConversionError::WrongCount {
+                expected: vec![3],
+                found: value.len(),
+            });
+        };
+
+        let lightness = parse_component(lightness, "L")?;
+
+        Ok(Self {
+            lightness: check_range(lightness, "L", 0.0..=100.0)?,
+            a: parse_component(a, "a")?,
+            b: parse_component(b, "b")?,
+        })
+    }
+}
+
+impl TryFrom<&[String]> for Lch {
+    type Error = ConversionError;
+
+    fn try_from(value: &[String]) -> Result<Self, Self::Error> {
+        let [lightness, chroma, hue] = value else {
+            return Err(ConversionError::WrongCount {
+                expected: vec![3],
+                found: value.len(),
+            });
+        };
+
+        let lightness = parse_component(lightness, "L")?;
+        let chroma = parse_component(chroma, "C")?;
+
+        Ok(Self {
+            lightness: check_range(lightness, "L", 0.0..=100.0)?,
+            chroma: check_range(chroma, "C", 0.0..=f64::INFINITY)?,
+            hue: parse_component(hue, "h")?.rem_euclid(360.0),
+        })
+    }
+}
+
+impl ColorSpace for Lab {
+    const NAME: &'static str = "lab";
+    const COMPONENTS: &'static [Component] = &[
+        Component::new("lightness", 0.0, 100.0),
+        Component::unbounded("a"),
+        Component::unbounded("b"),
+    ];
+
+    type Parent = Xyz;
+
+    fn to_parent(&self) -> Xyz {
+        Xyz::from(*self)
+    }
+
+    fn from_parent(xyz: Xyz) -> Self {
+        Self::from(xyz)
+    }
+
+    fn components(&self) -> Vec<f64> {
+        vec![self.lightness, self.a, self.b]
+    }
+
+    fn from_components(c: &[f64]) -> Self {
+        Self::from((component(c, 0), component(c, 1), component(c, 2)))
+    }
+
+    fn parse(values: &[String]) -> Result<Self, ConversionError> {
+        Self::try_from(values)
+    }
+}
+
+impl ColorSpace for Lch {
+    const NAME: &'static str = "lch";
+    const COMPONENTS: &'static [Component] = &[
+        Component::new("lightness", 0.0, 100.0),
+        Component::unbounded("chroma"),
+        Component::new("hue", 0.0, 360.0),
+    ];
+
+    type Parent = Lab;
+
+    fn to_parent(&self) -> Lab {
+        Lab::from(*self)
+    }
+
+    fn from_parent(lab: Lab) -> Self {
+        Self::from(lab)
+    }
+
+    fn components(&self) -> Vec<f64> {
+        vec![self.lightness, self.chroma, self.hue]
+    }
+
+    fn from_components(c: &[f64]) -> Self {
+        Self::from((component(c, 0), component(c, 1), component(c, 2)))
+    }
+
+    fn parse(values: &[String]) -> Result<Self, ConversionError> {
+        Self::try_from(values)
+    }
+}
+
+impl core::fmt::Display for Lab {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let precision = f.precision().unwrap_or(2);
+
+        f.write_fmt(format_args!(
+            "lab({}, {}, {})",
+            decimal(self.lightness, precision),
+            decimal(self.a, precision),
+            decimal(self.b, precision)
+        ))
+    }
+}
+
+impl core::fmt::Display for Lch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let precision = f.precision().unwrap_or(2);
+
+        // Hue of achromatic colors is meaningless, show it as zero.
+        let hue = if self.chroma < 0.5 * 10f64.powi(-(precision as i32)) {
+            0.0
+        } else {
+            self.hue
+        };
+
+        f.write_fmt(format_args!(
+            "lch({}, {}, {})",
+            decimal(self.lightness, precision),
+            decimal(self.chroma, precision),
+            decimal(hue, precision)
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::{format::Rgb, Color};
+
+    use super::{Lab, Lch};
+
+    #[test]
+    fn reference_values() {
+        // Values from CSS Color Module Level 4, e.g. sRGB red is
+        // lab(54.29% 80.8 69.89).
+        let red = Lab::from(Rgb::from((255, 0, 0)));
+        assert_eq!(red.to_string(), "lab(54.29, 80.80, 69.89)");
+
+        let white = Lch::from(Rgb::from((255, 255, 255)));
+        assert_eq!(white.to_string(), "lch(100.00, 0.00, 0.00)");
+    }
+
+    #[test]
+    fn roundtrip() {
+        let rgb = Rgb::from((51, 102, 204));
+        let lch = Lch::from(rgb);
+
+        assert_eq!(Rgb::from(Color::from(lch)).to_bytes(), (51, 102, 204));
+    }
+}
@@ -0,0 +1,153 @@
+use alloc::{format, string::String};
+
+use crate::{error::ConversionError, Color, LatexModel};
+
+use super::{decimal, trimmed_decimal, Rgb};
+
+/// LaTeX `xcolor` color definition, e.g. `\definecolor{brand}{RGB}{51,102,204}`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Latex<'a> {
+    name: &'a str,
+    model: LatexModel,
+}
+
+impl<'a> Latex<'a> {
+    pub fn new(name: &'a str, model: LatexModel) -> Self {
+        Self { name, model }
+    }
+
+    /// Formats color definition. Floating point values are written with
+    /// `precision` decimal places, or with at most four if none is given.
+    pub fn format(&self, color: Color, precision: Option<usize>) -> String {
+        let rgb = Rgb::from(color);
+        let (red, green, blue) = rgb.into_tuple();
+
+        let number = |value: f64| match precision {
+            Some(precision) => decimal(value, precision),
+            None => trimmed_decimal(value, 4),
+        };
+
+        let values = match self.model {
+            LatexModel::RgbInt => {
+                let (red, green, blue) = rgb.to_bytes();
+                format!("{red},{green},{blue}")
+            }
+            LatexModel::Rgb => [red, green, blue].map(number).join(","),
+            LatexModel::Html => rgb.to_hex().to_uppercase(),
+            LatexModel::Cmyk => {
+                // Inverse of the conversion used by `xcolor`, as when parsing
+                // `{cmyk}` definitions.
+                let key = 1.0 - red.max(green).max(blue);
+                let ink = |c: f64| {
+                    if key == 1.0 {
+                        0.0
+                    } else {
+                        (1.0 - c - key) / (1.0 - key)
+                    }
+                };
+
+                [ink(red), ink(green), ink(blue), key].map(number).join(",")
+            }
+            LatexModel::Gray => number(0.3 * red + 0.59 * green + 0.11 * blue),
+        };
+
+        format!(
+            "\\definecolor{{{}}}{{{}}}{{{values}}}",
+            self.name,
+            self.model.name()
+        )
+    }
+}
+
+/// Checks that name of a LaTeX color consists of ASCII letters and digits,
+/// as e.g. `_`, `#` and `%` are special to TeX and break the definition.
+pub fn check_color_name(name: &str) -> Result<&str, ConversionError> {
+    if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric()) {
+        Ok(name)
+    } else {
+        Err(ConversionError::InvalidColorName {
+            name: String::from(name),
+        })
+    }
+}
+
+impl LatexModel {
+    /// Name of the color model as understood by `xcolor`.
+    pub fn name(self) -> &'static str {
+        match self {
+            LatexModel::RgbInt => "RGB",
+            LatexModel::Rgb => "rgb",
+            LatexModel::Html => "HTML",
+            LatexModel::Cmyk => "cmyk",
+            LatexModel::Gray => "gray",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use crate::{error::ConversionError, format::Rgb, Color, LatexModel};
+
+    use super::{check_color_name, Latex};
+
+    fn format(model: LatexModel) -> String {
+        Latex::new("brand", model).format(Color::from(Rgb::from((51, 102, 204))), None)
+    }
+
+    #[test]
+    fn rgb_int() {
+        assert_eq!(
+            format(LatexModel::RgbInt),
+            "\\definecolor{brand}{RGB}{51,102,204}"
+        );
+    }
+
+    #[test]
+    fn rgb() {
+        assert_eq!(
+            format(LatexModel::Rgb),
+            "\\definecolor{brand}{rgb}{0.2,0.4,0.8}"
+        );
+    }
+
+    #[test]
+    fn html() {
+        assert_eq!(
+            format(LatexModel::Html),
+            "\\definecolor{brand}{HTML}{3366CC}"
+        );
+    }
+
+    #[test]
+    fn cmyk() {
+        assert_eq!(
+            format(LatexModel::Cmyk),
+            "\\definecolor{brand}{cmyk}{0.75,0.5,0,0.2}"
+        );
+    }
+
+    #[test]
+    fn gray() {
+        assert_eq!(
+            format(LatexModel::Gray),
+            "\\definecolor{brand}{gray}{0.384}"
+        );
+    }
+
+    #[test]
+    fn color_name() {
+        assert_eq!(check_color_name("brand1"), Ok("brand1"));
+
+        for name in ["", "brand_blue", "#1", "50%", "brand blue"] {
+            assert_eq!(
+                check_color_name(name),
+                Err(ConversionError::InvalidColorName {
+                    name: String::from(name)
+                }),
+                "name: {name:?}"
+            );
+        }
+    }
+}
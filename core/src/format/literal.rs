@@ -0,0 +1,207 @@
+use alloc::{format, string::String};
+
+use crate::{Color, Options};
+
+use super::{decimal, to_byte, trimmed_decimal, Rgb};
+
+/// Color literals of programming languages and UI frameworks, ready to be
+/// pasted into source code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Literal {
+    /// Rust tuple, e.g. `(51, 102, 204)`.
+    RustTuple,
+    /// Rust struct, e.g. `Rgb { r: 51, g: 102, b: 204 }`.
+    RustStruct,
+    /// C array or struct initializer, e.g. `{51, 102, 204}`.
+    C,
+    /// SwiftUI color, e.g. `Color(red: 0.2, green: 0.4, blue: 0.8)`.
+    SwiftUi,
+    /// UIKit color, e.g. `UIColor(red: 0.2, green: 0.4, blue: 0.8, alpha: 1.0)`.
+    UiKit,
+    /// Android XML color resource named by [`Options::name`], e.g.
+    /// `<color name="brand">#FF3366CC</color>`.
+    AndroidXml,
+    /// Flutter color, e.g. `Color(0xFF3366CC)`.
+    Flutter,
+    /// Unity color, e.g. `new Color(0.2f, 0.4f, 0.8f)`.
+    Unity,
+    /// GLSL vector, e.g. `vec3(0.2, 0.4, 0.8)`.
+    GlslVec3,
+    /// GLSL vector with alpha, e.g. `vec4(0.2, 0.4, 0.8, 1.0)`.
+    GlslVec4,
+    /// Kotlin Jetpack Compose color, e.g. `Color(0xFF3366CC)`.
+    Compose,
+}
+
+impl Literal {
+    /// Formats color as literal. Floating point channels are written with
+    /// precision of `options`, or with at most three decimal places if none
+    /// is given.
+    pub fn format(self, color: Color, options: &Options) -> String {
+        let precision = options.precision;
+        let rgb = Rgb::from(color);
+        let (red, green, blue) = rgb.to_bytes();
+        let [r, g, b, a] = [color.red, color.green, color.blue, color.alpha]
+            .map(|channel| unit_float(channel, precision));
+        let argb = format!(
+            "{:02X}{}",
+            to_byte(color.alpha),
+            rgb.to_hex().to_uppercase()
+        );
+
+        match self {
+            Literal::RustTuple => format!("({red}, {green}, {blue})"),
+            Literal::RustStruct => format!("Rgb {{ r: {red}, g: {green}, b: {blue} }}"),
+            Literal::C => format!("{{{red}, {green}, {blue}}}"),
+            Literal::SwiftUi => format!("Color(red: {r}, green: {g}, blue: {b})"),
+            Literal::UiKit => format!("UIColor(red: {r}, green: {g}, blue: {b}, alpha: {a})"),
+            Literal::AndroidXml => {
+                format!(
+                    "<color name=\"{}\">#{argb}</color>",
+                    escape_xml(&options.name)
+                )
+            }
+            Literal::Flutter | Literal::Compose => format!("Color(0x{argb})"),
+            Literal::Unity => format!("new Color({r}f, {g}f, {b}f)"),
+            Literal::GlslVec3 => format!("vec3({r}, {g}, {b})"),
+            Literal::GlslVec4 => format!("vec4({r}, {g}, {b}, {a})"),
+        }
+    }
+}
+
+/// Escapes characters special in XML attribute values.
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('"', "&quot;")
+}
+
+/// Formats channel value as floating point number in range 0..1, by default
+/// with at most three decimal places, e.g. `0.2`, `0.502` or `1.0`.
+fn unit_float(channel: f64, precision: Option<usize>) -> String {
+    match precision {
+        Some(precision) => decimal(channel, precision),
+        None => {
+            let trimmed = trimmed_decimal(channel, 3);
+
+            if trimmed.contains('.') {
+                trimmed
+            } else {
+                format!("{trimmed}.0")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::String;
+
+    use crate::{format::Rgb, Color, Options};
+
+    use super::Literal;
+
+    fn format(literal: Literal) -> String {
+        let options = Options {
+            name: String::from("brand"),
+            ..Options::default()
+        };
+
+        literal.format(Color::from(Rgb::from((51, 102, 204))), &options)
+    }
+
+    #[test]
+    fn rust() {
+        assert_eq!(format(Literal::RustTuple), "(51, 102, 204)");
+        assert_eq!(format(Literal::RustStruct), "Rgb { r: 51, g: 102, b: 204 }");
+    }
+
+    #[test]
+    fn c() {
+        assert_eq!(format(Literal::C), "{51, 102, 204}");
+    }
+
+    #[test]
+    fn swift() {
+        assert_eq!(
+            format(Literal::SwiftUi),
+            "Color(red: 0.2, green: 0.4, blue: 0.8)"
+        );
+        assert_eq!(
+            format(Literal::UiKit),
+            "UIColor(red: 0.2, green: 0.4, blue: 0.8, alpha: 1.0)"
+        );
+    }
+
+    #[test]
+    fn android() {
+        assert_eq!(
+            format(Literal::AndroidXml),
+            "<color name=\"brand\">#FF3366CC</color>"
+        );
+        assert_eq!(
+            Literal::AndroidXml.format(
+                Color::from(Rgb::from((51, 102, 204))),
+                &Options {
+                    name: String::from("a\"b"),
+                    ..Options::default()
+                }
+            ),
+            "<color name=\"a&quot;b\">#FF3366CC</color>"
+        );
+        assert_eq!(format(Literal::Compose), "Color(0xFF3366CC)");
+    }
+
+    #[test]
+    fn flutter() {
+        assert_eq!(format(Literal::Flutter), "Color(0xFF3366CC)");
+    }
+
+    #[test]
+    fn unity() {
+        assert_eq!(format(Literal::Unity), "new Color(0.2f, 0.4f, 0.8f)");
+    }
+
+    #[test]
+    fn glsl() {
+        assert_eq!(format(Literal::GlslVec3), "vec3(0.2, 0.4, 0.8)");
+        assert_eq!(format(Literal::GlslVec4), "vec4(0.2, 0.4, 0.8, 1.0)");
+    }
+
+    #[test]
+    fn fractional_channels() {
+        assert_eq!(
+            Literal::GlslVec3.format(Color::from(Rgb::from((0, 128, 255))), &Options::default()),
+            "vec3(0.0, 0.502, 1.0)"
+        );
+    }
+
+    #[test]
+    fn alpha() {
+        let color = Color::from_unit_rgba(0.2, 0.4, 0.8, 0.5);
+
+        assert_eq!(
+            Literal::Flutter.format(color, &Options::default()),
+            "Color(0x803366CC)"
+        );
+        assert_eq!(
+            Literal::GlslVec4.format(color, &Options::default()),
+            "vec4(0.2, 0.4, 0.8, 0.5)"
+        );
+    }
+
+    #[test]
+    fn precision() {
+        let color = Color::from_unit_rgba(0.2, 0.4, 0.8, 1.0);
+        let options = Options {
+            precision: Some(2),
+            ..Options::default()
+        };
+
+        assert_eq!(
+            Literal::SwiftUi.format(color, &options),
+            "Color(red: 0.20, green: 0.40, blue: 0.80)"
+        );
+    }
+}
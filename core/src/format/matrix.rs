@@ -0,0 +1,78 @@
+//! Minimal 3x3 matrix arithmetic used to derive conversions between color
+//! spaces at runtime.
+
+/// Row-major 3x3 matrix.
+pub(crate) type Matrix = [[f64; 3]; 3];
+
+/// Multiplies two matrices.
+pub(crate) fn multiply(a: &Matrix, b: &Matrix) -> Matrix {
+    let mut product = [[0.0; 3]; 3];
+
+    for (row, a_row) in product.iter_mut().zip(a) {
+        for (col, value) in row.iter_mut().enumerate() {
+            *value = (0..3).map(|k| a_row[k] * b[k][col]).sum();
+        }
+    }
+
+    product
+}
+
+/// Multiplies matrix with column vector.
+pub(crate) fn apply(m: &Matrix, (x, y, z): (f64, f64, f64)) -> (f64, f64, f64) {
+    let row = |r: &[f64; 3]| r[0] * x + r[1] * y + r[2] * z;
+
+    (row(&m[0]), row(&m[1]), row(&m[2]))
+}
+
+/// Returns inverse of the matrix, or `None` if the matrix is singular.
+pub(crate) fn invert(m: &Matrix) -> Option<Matrix> {
+    let [[a, b, c], [d, e, f], [g, h, i]] = *m;
+
+    let cofactors = [
+        [e * i - f * h, c * h - b * i, b * f - c * e],
+        [f * g - d * i, a * i - c * g, c * d - a * f],
+        [d * h - e * g, b * g - a * h, a * e - b * d],
+    ];
+    let determinant = a * cofactors[0][0] + b * cofactors[1][0] + c * cofactors[2][0];
+
+    if determinant.abs() < 1e-12 {
+        return None;
+    }
+
+    Some(cofactors.map(|row| row.map(|value| value / determinant)))
+}
+
+/// Returns diagonal matrix with given values.
+pub(crate) fn diagonal((x, y, z): (f64, f64, f64)) -> Matrix {
+    [[x, 0.0, 0.0], [0.0, y, 0.0], [0.0, 0.0, z]]
+}
+
+/// Converts chromaticity coordinates to XYZ with luminance of 1.
+pub(crate) fn xy_to_xyz((x, y): (f64, f64)) -> (f64, f64, f64) {
+    (x / y, 1.0, (1.0 - x - y) / y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{apply, invert, multiply};
+
+    #[test]
+    fn inverse() {
+        let m = [[2.0, 0.0, 1.0], [1.0, 3.0, 0.0], [0.0, 1.0, 4.0]];
+        let identity = multiply(&m, &invert(&m).unwrap());
+
+        for (r, row) in identity.iter().enumerate() {
+            for (c, value) in row.iter().enumerate() {
+                let expected = if r == c { 1.0 } else { 0.0 };
+                assert!((value - expected).abs() < 1e-12);
+            }
+        }
+
+        assert_eq!(apply(&m, (1.0, 1.0, 1.0)), (3.0, 4.0, 5.0));
+    }
+
+    #[test]
+    fn singular() {
+        assert!(invert(&[[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 0.0]]).is_none());
+    }
+}
@@ -0,0 +1,282 @@
+//! Mixing of colors as defined by CSS `color-mix()`.
+
+use crate::{Color, HueInterpolation, InterpolationSpace};
+
+use super::{Hsl, Hsv, Lab, Lch, Oklab, Oklch, Rgb, Xyz};
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+
+/// Difference of sRGB channels below which a color is considered gray, so its
+/// hue is powerless.
+const ACHROMATIC: f64 = 1e-5;
+
+/// Mixes `color` with `ratio` of `other`, premultiplying components by alpha
+/// and interpolating them linearly in given space.
+pub(crate) fn mix(
+    color: Color,
+    other: Color,
+    ratio: f64,
+    space: InterpolationSpace,
+    hue: HueInterpolation,
+) -> Color {
+    let ratio = ratio.clamp(0.0, 1.0);
+    let lerp = |a: f64, b: f64| a + (b - a) * ratio;
+
+    let mut first = space.components(Rgb::from(color));
+    let mut second = space.components(Rgb::from(other));
+
+    if let Some(index) = space.hue() {
+        // Hue of gray is powerless, so the hue of the other color is used.
+        match (is_achromatic(color), is_achromatic(other)) {
+            (true, false) => first[index] = second[index],
+            (false, true) => second[index] = first[index],
+            _ => {}
+        }
+
+        (first[index], second[index]) = hue.adjust(first[index], second[index]);
+    }
+
+    let alpha = lerp(color.alpha(), other.alpha());
+    let mut mixed = [0.0; 3];
+
+    for (index, component) in mixed.iter_mut().enumerate() {
+        *component = if space.hue() == Some(index) {
+            lerp(first[index], second[index]).rem_euclid(360.0)
+        } else {
+            let premultiplied = lerp(first[index] * color.alpha(), second[index] * other.alpha());
+
+            if alpha == 0.0 {
+                premultiplied
+            } else {
+                premultiplied / alpha
+            }
+        };
+    }
+
+    Color::from(space.to_rgb(mixed)).with_alpha(alpha)
+}
+
+fn is_achromatic(color: Color) -> bool {
+    let channels = [color.red(), color.green(), color.blue()];
+    let max = channels.iter().copied().fold(f64::MIN, f64::max);
+    let min = channels.iter().copied().fold(f64::MAX, f64::min);
+
+    max - min < ACHROMATIC
+}
+
+impl InterpolationSpace {
+    /// Returns index of the hue component in cylindrical spaces.
+    fn hue(self) -> Option<usize> {
+        match self {
+            InterpolationSpace::Lch | InterpolationSpace::Oklch => Some(2),
+            InterpolationSpace::Hsl | InterpolationSpace::Hwb => Some(0),
+            _ => None,
+        }
+    }
+
+    /// Converts color into components of the space.
+    fn components(self, rgb: Rgb) -> [f64; 3] {
+        match self {
+            InterpolationSpace::Srgb => rgb.into_tuple().into(),
+            InterpolationSpace::SrgbLinear => rgb.to_linear().into(),
+            InterpolationSpace::Lab => {
+                let lab = Lab::from(rgb);
+
+                [lab.lightness(), lab.a(), lab.b()]
+            }
+            InterpolationSpace::Lch => {
+                let lch = Lch::from(rgb);
+
+                [lch.lightness(), lch.chroma(), lch.hue()]
+            }
+            InterpolationSpace::Oklab => {
+                let oklab = Oklab::from(rgb);
+
+                [oklab.lightness(), oklab.a(), oklab.b()]
+            }
+            InterpolationSpace::Oklch => {
+                let oklch = Oklch::from(rgb);
+
+                [oklch.lightness(), oklch.chroma(), oklch.hue()]
+            }
+            InterpolationSpace::Hsl => Hsl::from(rgb).into_tuple().into(),
+            InterpolationSpace::Hwb => hwb(Hsv::from(rgb)),
+            InterpolationSpace::Xyz => Xyz::from(rgb).into_tuple().into(),
+        }
+    }
+
+    /// Converts components of the space back into sRGB.
+    fn to_rgb(self, [a, b, c]: [f64; 3]) -> Rgb {
+        match self {
+            InterpolationSpace::Srgb => Rgb::from((a, b, c)),
+            InterpolationSpace::SrgbLinear => Rgb::from_linear((a, b, c)),
+            InterpolationSpace::Lab => Rgb::from(Lab::from((a, b, c))),
+            InterpolationSpace::Lch => Rgb::from(Lab::from(Lch::from((a, b, c)))),
+            InterpolationSpace::Oklab => Rgb::from(Oklab::from((a, b, c))),
+            InterpolationSpace::Oklch => Rgb::from(Oklab::from(Oklch::from((a, b, c)))),
+            InterpolationSpace::Hsl => Rgb::from(Hsl::from((a, b, c))),
+            InterpolationSpace::Hwb => Rgb::from(from_hwb([a, b, c])),
+            InterpolationSpace::Xyz => Xyz::from((a, b, c)).to_rgb(),
+        }
+    }
+}
+
+impl HueInterpolation {
+    /// Adjusts hues in degrees, so that linear interpolation between them
+    /// follows the arc of the method.
+    fn adjust(self, first: f64, second: f64) -> (f64, f64) {
+        let delta = second - first;
+
+        match self {
+            HueInterpolation::Shorter if delta > 180.0 => (first + 360.0, second),
+            HueInterpolation::Shorter if delta < -180.0 => (first, second + 360.0),
+            HueInterpolation::Longer if 0.0 < delta && delta < 180.0 => (first + 360.0, second),
+            HueInterpolation::Longer if -180.0 < delta && delta <= 0.0 => (first, second + 360.0),
+            HueInterpolation::Increasing if delta < 0.0 => (first, second + 360.0),
+            HueInterpolation::Decreasing if delta > 0.0 => (first + 360.0, second),
+            _ => (first, second),
+        }
+    }
+}
+
+/// Converts HSV to hue, whiteness and blackness in percent.
+fn hwb(hsv: Hsv) -> [f64; 3] {
+    let (hue, saturation, value) = hsv.into_tuple();
+
+    [hue, (100.0 - saturation) * value / 100.0, 100.0 - value]
+}
+
+fn from_hwb([hue, whiteness, blackness]: [f64; 3]) -> Hsv {
+    if whiteness + blackness >= 100.0 {
+        let gray = whiteness / (whiteness + blackness) * 100.0;
+
+        return Hsv::from((hue, 0.0, gray));
+    }
+
+    let value = 100.0 - blackness;
+
+    Hsv::from((hue, 100.0 - whiteness / value * 100.0, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::{
+        format::{Hsl, Lch},
+        Color, HueInterpolation, InterpolationSpace,
+    };
+
+    #[test]
+    fn srgb() {
+        let red = Color::from_hex_u32(0xff0000);
+        let blue = Color::from_hex_u32(0x0000ff);
+
+        let mixed = red.mix(blue, 0.5, InterpolationSpace::Srgb);
+        assert_eq!(mixed.to_string(), "800080");
+
+        let mixed = red.mix(blue, 0.25, InterpolationSpace::Srgb);
+        assert_eq!(mixed.to_string(), "bf0040");
+
+        let black = Color::from_hex_u32(0x000000);
+        let white = Color::from_hex_u32(0xffffff);
+        let mixed = black.mix(white, 0.5, InterpolationSpace::SrgbLinear);
+        assert_eq!(mixed.to_string(), "bcbcbc");
+    }
+
+    #[test]
+    fn premultiplied_alpha() {
+        // color-mix(in srgb, rgb(255 0 0 / 0.5), blue) is rgb(85 0 170 / 0.75).
+        let red = Color::new(1.0, 0.0, 0.0, 0.5);
+        let blue = Color::from_hex_u32(0x0000ff);
+
+        let mixed = red.mix(blue, 0.5, InterpolationSpace::Srgb);
+        assert_eq!(mixed.to_string(), "5500aabf");
+
+        let transparent = Color::new(0.0, 0.0, 0.0, 0.0);
+        let mixed = transparent.mix(blue, 0.5, InterpolationSpace::Oklab);
+        assert_eq!(mixed.to_string(), "0000ff80");
+    }
+
+    #[test]
+    fn lch() {
+        // Example of CSS Color Module Level 5, purple and plum mixed in LCh
+        // are lch(51.51% 52.21 325.8).
+        let purple = Color::from_hex_u32(0x800080);
+        let plum = Color::from_hex_u32(0xdda0dd);
+
+        let mixed = purple.mix(plum, 0.5, InterpolationSpace::Lch);
+        let lch = Lch::from(mixed);
+        let (l, c, h) = (lch.lightness(), lch.chroma(), lch.hue());
+
+        assert!((l - 51.51).abs() < 0.05, "{l}");
+        assert!((c - 52.21).abs() < 0.1, "{c}");
+        assert!((h - 325.8).abs() < 0.1, "{h}");
+    }
+
+    #[test]
+    fn hue_interpolation() {
+        let first = Color::from(Hsl::from((10.0, 100.0, 50.0)));
+        let second = Color::from(Hsl::from((350.0, 100.0, 50.0)));
+
+        let hue = |method| {
+            let mixed = first.mix_with(second, 0.5, InterpolationSpace::Hsl, method);
+
+            crate::format::Hsl::from(mixed)
+                .hue()
+                .round()
+                .rem_euclid(360.0)
+        };
+
+        assert_eq!(hue(HueInterpolation::Shorter), 0.0);
+        assert_eq!(hue(HueInterpolation::Longer), 180.0);
+        assert_eq!(hue(HueInterpolation::Increasing), 180.0);
+        assert_eq!(hue(HueInterpolation::Decreasing), 0.0);
+    }
+
+    #[test]
+    fn powerless_hue() {
+        let white = Color::from_hex_u32(0xffffff);
+        let blue = Color::from_hex_u32(0x0000ff);
+
+        for space in [
+            InterpolationSpace::Lch,
+            InterpolationSpace::Oklch,
+            InterpolationSpace::Hsl,
+            InterpolationSpace::Hwb,
+        ] {
+            let index = space.hue().unwrap();
+            let mixed = space.components(white.mix(blue, 0.5, space).into());
+            let expected = space.components(blue.into());
+
+            assert!((mixed[index] - expected[index]).abs() < 1e-6, "{space:?}");
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let color = Color::from_hex_u32(0x3366cc);
+
+        for space in [
+            InterpolationSpace::Srgb,
+            InterpolationSpace::SrgbLinear,
+            InterpolationSpace::Lab,
+            InterpolationSpace::Lch,
+            InterpolationSpace::Oklab,
+            InterpolationSpace::Oklch,
+            InterpolationSpace::Hsl,
+            InterpolationSpace::Hwb,
+            InterpolationSpace::Xyz,
+        ] {
+            assert_eq!(color.mix(color, 0.3, space).to_string(), "3366cc");
+            assert_eq!(
+                color
+                    .mix(Color::from_hex_u32(0xffffff), 0.0, space)
+                    .to_string(),
+                "3366cc",
+                "{space:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,204 @@
+//! Color formats and color spaces supported by boja.
+//!
+//! Every format can be created from [`Color`](crate::Color), or from [`Rgb`]
+//! for formats that need additional parameters, e.g. [`Pq::new`], and
+//! converted back into [`Color`](crate::Color). Formats are written in their
+//! textual representation using [`Display`](core::fmt::Display), where the
+//! precision of the formatter sets the number of decimal places, e.g.
+//! `format!("{hsl:.2}")`.
+
+use core::ops::RangeInclusive;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+use crate::error::ConversionError;
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+
+/// Implements getters of `f64` fields of a format.
+macro_rules! getters {
+    ($format:ty { $($(#[$doc:meta])* $field:ident),+ $(,)? }) => {
+        impl $format {
+            $(
+                $(#[$doc])*
+                pub fn $field(&self) -> f64 {
+                    self.$field
+                }
+            )+
+        }
+    };
+}
+
+/// Implements conversion from [`Color`](crate::Color) for formats that can be
+/// converted from [`Rgb`].
+macro_rules! from_color {
+    ($($format:ty),+ $(,)?) => {
+        $(
+            impl From<crate::Color> for $format {
+                fn from(color: crate::Color) -> Self {
+                    <$format>::from(Rgb::from(color))
+                }
+            }
+        )+
+    };
+}
+
+/// Implements [`FromStr`](std::str::FromStr) for formats written in functional
+/// notation of their color space, e.g. `hsl(220, 60, 50)`, using the parser of
+/// the format from the grammar of [`parse_color`](crate::parse_color).
+macro_rules! from_str {
+    ($($format:ty => $parser:ident),+ $(,)?) => {
+        $(
+            #[cfg(feature = "std")]
+            impl std::str::FromStr for $format {
+                type Err = crate::error::ParseError;
+
+                fn from_str(input: &str) -> Result<Self, Self::Err> {
+                    crate::parser::parse_function(input, crate::parser::$parser())
+                }
+            }
+        )+
+    };
+}
+
+mod adaptation;
+mod color_space;
+mod gamut;
+#[cfg(feature = "std")]
+mod graph;
+mod hdr;
+mod hex;
+mod hsl;
+mod hsv;
+mod ictcp;
+mod jzazbz;
+mod lab;
+mod latex;
+mod literal;
+mod matrix;
+mod mix;
+mod oklab;
+mod rec2020;
+mod registry;
+mod rgb;
+mod rgb48;
+mod scrgb;
+mod space;
+mod xyy;
+mod xyz;
+
+pub use color_space::*;
+#[cfg(feature = "std")]
+pub use graph::*;
+pub use hdr::*;
+pub(crate) use hex::*;
+pub use hsl::*;
+pub use hsv::*;
+pub use ictcp::*;
+pub use jzazbz::*;
+pub use lab::*;
+pub use latex::check_color_name;
+pub(crate) use latex::*;
+pub(crate) use literal::*;
+pub(crate) use mix::*;
+pub use oklab::*;
+pub use rec2020::*;
+pub(crate) use registry::*;
+pub use rgb::*;
+pub use rgb48::*;
+pub use scrgb::*;
+pub use space::*;
+pub use xyy::*;
+pub use xyz::*;
+
+from_color!(Hsl, Hsv, Lab, Lch, Oklab, Oklch, Rgb48, Scrgb, Xyy, Xyz);
+
+from_str!(
+    Hlg => hlg,
+    Hsl => hsl,
+    Hsv => hsv,
+    Ictcp => ictcp,
+    Jzazbz => jzazbz,
+    Jzczhz => jzczhz,
+    Lab => lab,
+    Lch => lch,
+    Oklab => oklab,
+    Oklch => oklch,
+    Pq => pq,
+    Rgb => rgb,
+    Rgb48 => rgb48,
+    Scrgb => scrgb,
+    Xyy => xyy,
+    Xyz => xyz,
+);
+
+/// Parses value of given component.
+pub(crate) fn parse_component(
+    value: &str,
+    component: &'static str,
+) -> Result<f64, ConversionError> {
+    value.parse::<f64>().map_err(|_| ConversionError::Parse {
+        component,
+        value: value.to_string(),
+    })
+}
+
+/// Checks that value of given component is within its valid range.
+pub(crate) fn check_range(
+    value: f64,
+    component: &'static str,
+    range: RangeInclusive<f64>,
+) -> Result<f64, ConversionError> {
+    if range.contains(&value) {
+        Ok(value)
+    } else {
+        Err(ConversionError::OutOfRange {
+            component,
+            range,
+            value,
+        })
+    }
+}
+
+/// Checks that number of values is one of the expected ones.
+pub(crate) fn check_count<T>(values: &[T], expected: &[usize]) -> Result<(), ConversionError> {
+    if expected.contains(&values.len()) {
+        Ok(())
+    } else {
+        Err(ConversionError::WrongCount {
+            expected: expected.to_vec(),
+            found: values.len(),
+        })
+    }
+}
+
+/// Formats value with given number of decimal places, rounding half away from
+/// zero like [`f64::round`].
+pub(crate) fn decimal(value: f64, precision: usize) -> String {
+    let factor = 10f64.powi(precision as i32);
+    let rounded = (value * factor).round() / factor;
+
+    // Avoid printing negative zero, e.g. for values like -0.0001.
+    let rounded = if rounded == 0.0 { 0.0 } else { rounded };
+
+    format!("{rounded:.precision$}")
+}
+
+/// Formats value with at most `precision` decimal places, without trailing
+/// zeros, e.g. `0.2` or `0.502`.
+pub(crate) fn trimmed_decimal(value: f64, precision: usize) -> String {
+    let formatted = decimal(value, precision);
+
+    if formatted.contains('.') {
+        formatted
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
+    } else {
+        formatted
+    }
+}
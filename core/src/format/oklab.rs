@@ -0,0 +1,316 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::{error::ConversionError, Color};
+
+use super::{check_range, component, decimal, parse_component, ColorSpace, Component, Rgb};
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+
+/// Oklab perceptual color, e.g. `oklab(0.5630, -0.0107, -0.1550)`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Oklab {
+    lightness: f64,
+    a: f64,
+    b: f64,
+}
+
+/// Oklch color, i.e. Oklab in polar coordinates with hue in degrees, e.g.
+/// `oklch(0.5630, 0.1554, 266.0500)`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Oklch {
+    lightness: f64,
+    chroma: f64,
+    hue: f64,
+}
+
+impl From<Rgb> for Oklab {
+    fn from(rgb: Rgb) -> Self {
+        let (r, g, b) = rgb.to_linear();
+
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+        Self {
+            lightness: 0.2104542553 * l + 0.793617785 * m - 0.0040720468 * s,
+            a: 1.9779984951 * l - 2.428592205 * m + 0.4505937099 * s,
+            b: 0.0259040371 * l + 0.7827717662 * m - 0.808675766 * s,
+        }
+    }
+}
+
+impl From<Oklab> for Rgb {
+    /// Converts to sRGB. Colors outside of the sRGB gamut have channels outside
+    /// of range 0..1.
+    fn from(oklab: Oklab) -> Self {
+        let Oklab { lightness, a, b } = oklab;
+
+        let l = (lightness + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+        let m = (lightness - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+        let s = (lightness - 0.0894841775 * a - 1.291485548 * b).powi(3);
+
+        Rgb::from_linear((
+            4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+            -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+            -0.0041960863 * l - 0.7034186147 * m + 1.707614701 * s,
+        ))
+    }
+}
+
+impl From<Oklab> for Color {
+    fn from(oklab: Oklab) -> Self {
+        Color::from(Rgb::from(oklab))
+    }
+}
+
+impl Oklab {
+    /// Euclidean distance of two colors, known as deltaEOK.
+    pub fn distance(&self, other: &Oklab) -> f64 {
+        let dl = self.lightness - other.lightness;
+        let da = self.a - other.a;
+        let db = self.b - other.b;
+
+        (dl * dl + da * da + db * db).sqrt()
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(oklab: Oklab) -> Self {
+        Self {
+            lightness: oklab.lightness,
+            chroma: oklab.a.hypot(oklab.b),
+            hue: oklab.b.atan2(oklab.a).to_degrees().rem_euclid(360.0),
+        }
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(oklch: Oklch) -> Self {
+        let (sin, cos) = oklch.hue.to_radians().sin_cos();
+
+        Self {
+            lightness: oklch.lightness,
+            a: oklch.chroma * cos,
+            b: oklch.chroma * sin,
+        }
+    }
+}
+
+impl From<Rgb> for Oklch {
+    fn from(rgb: Rgb) -> Self {
+        Oklch::from(Oklab::from(rgb))
+    }
+}
+
+impl From<Oklch> for Color {
+    fn from(oklch: Oklch) -> Self {
+        Color::from(Oklab::from(oklch))
+    }
+}
+
+impl From<(f64, f64, f64)> for Oklab {
+    fn from((lightness, a, b): (f64, f64, f64)) -> Self {
+        Self { lightness, a, b }
+    }
+}
+
+impl From<(f64, f64, f64)> for Oklch {
+    fn from((lightness, chroma, hue): (f64, f64, f64)) -> Self {
+        Self {
+            lightness,
+            chroma,
+            hue: hue.rem_euclid(360.0),
+        }
+    }
+}
+
+getters!(Oklab {
+    /// Perceived lightness in range 0..1.
+    lightness,
+    /// Green-red component.
+    a,
+    /// Blue-yellow component.
+    b,
+});
+
+getters!(Oklch {
+    /// Perceived lightness in range 0..1.
+    lightness,
+    /// Chroma, 0 for achromatic colors.
+    chroma,
+    /// Hue in degrees, in range 0..360.
+    hue,
+});
+
+impl Oklch {
+    /// Returns the same color with given chroma.
+    pub fn with_chroma(self, chroma: f64) -> Self {
+        Self { chroma, ..self }
+    }
+}
+
+impl TryFrom<&[String]> for Oklab {
+    type Error = ConversionError;
+
+    fn try_from(value: &[String]) -> Result<Self, Self::Error> {
+        let [lightness, a, b] = value else {
+            return Err(ConversionError::WrongCount {
+                expected: vec![3],
+                found: value.len(),
+            });
+        };
+
+        let lightness = parse_component(lightness, "L")?;
+
+        Ok(Self {
+            lightness: check_range(lightness, "L", 0.0..=1.0)?,
+            a: parse_component(a, "a")?,
+            b: parse_component(b, "b")?,
+        })
+    }
+}
+
+impl TryFrom<&[String]> for Oklch {
+    type Error = ConversionError;
+
+    fn try_from(value: &[String]) -> Result<Self, Self::Error> {
+        let [lightness, chroma, hue] = value else {
+            return Err(ConversionError::WrongCount {
+                expected: vec![3],
+                found: value.len(),
+            });
+        };
+
+        let lightness = parse_component(lightness, "L")?;
+        let chroma = parse_component(chroma, "C")?;
+
+        Ok(Self {
+            lightness: check_range(lightness, "L", 0.0..=1.0)?,
+            chroma: check_range(chroma, "C", 0.0..=f64::INFINITY)?,
+            hue: parse_component(hue, "h")?.rem_euclid(360.0),
+        })
+    }
+}
+
+impl ColorSpace for Oklab {
+    const NAME: &'static str = "oklab";
+    const COMPONENTS: &'static [Component] = &[
+        Component::new("lightness", 0.0, 1.0),
+        Component::unbounded("a"),
+        Component::unbounded("b"),
+    ];
+
+    type Parent = Rgb;
+
+    fn to_parent(&self) -> Rgb {
+        Rgb::from(*self)
+    }
+
+    fn from_parent(rgb: Rgb) -> Self {
+        Self::from(rgb)
+    }
+
+    fn components(&self) -> Vec<f64> {
+        vec![self.lightness, self.a, self.b]
+    }
+
+    fn from_components(c: &[f64]) -> Self {
+        Self::from((component(c, 0), component(c, 1), component(c, 2)))
+    }
+
+    fn parse(values: &[String]) -> Result<Self, ConversionError> {
+        Self::try_from(values)
+    }
+}
+
+impl ColorSpace for Oklch {
+    const NAME: &'static str = "oklch";
+    const COMPONENTS: &'static [Component] = &[
+        Component::new("lightness", 0.0, 1.0),
+        Component::unbounded("chroma"),
+        Component::new("hue", 0.0, 360.0),
+    ];
+
+    type Parent = Oklab;
+
+    fn to_parent(&self) -> Oklab {
+        Oklab::from(*self)
+    }
+
+    fn from_parent(oklab: Oklab) -> Self {
+        Self::from(oklab)
+    }
+
+    fn components(&self) -> Vec<f64> {
+        vec![self.lightness, self.chroma, self.hue]
+    }
+
+    fn from_components(c: &[f64]) -> Self {
+        Self::from((component(c, 0), component(c, 1), component(c, 2)))
+    }
+
+    fn parse(values: &[String]) -> Result<Self, ConversionError> {
+        Self::try_from(values)
+    }
+}
+
+impl core::fmt::Display for Oklab {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let precision = f.precision().unwrap_or(4);
+
+        f.write_fmt(format_args!(
+            "oklab({}, {}, {})",
+            decimal(self.lightness, precision),
+            decimal(self.a, precision),
+            decimal(self.b, precision)
+        ))
+    }
+}
+
+impl core::fmt::Display for Oklch {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let precision = f.precision().unwrap_or(4);
+
+        // Hue of achromatic colors is meaningless, show it as zero.
+        let hue = if self.chroma < 0.5 * 10f64.powi(-(precision as i32)) {
+            0.0
+        } else {
+            self.hue
+        };
+
+        f.write_fmt(format_args!(
+            "oklch({}, {}, {})",
+            decimal(self.lightness, precision),
+            decimal(self.chroma, precision),
+            decimal(hue, precision)
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::{format::Rgb, Color};
+
+    use super::{Oklab, Oklch};
+
+    #[test]
+    fn reference_values() {
+        // Values from CSS Color Module Level 4 examples.
+        let red = Oklab::from(Rgb::from((255, 0, 0)));
+        assert_eq!(red.to_string(), "oklab(0.6280, 0.2249, 0.1258)");
+
+        let white = Oklch::from(Rgb::from((255, 255, 255)));
+        assert_eq!(white.to_string(), "oklch(1.0000, 0.0000, 0.0000)");
+    }
+
+    #[test]
+    fn roundtrip() {
+        let rgb = Rgb::from((51, 102, 204));
+        let oklch = Oklch::from(rgb);
+
+        assert_eq!(Rgb::from(Color::from(oklch)).to_bytes(), (51, 102, 204));
+    }
+}
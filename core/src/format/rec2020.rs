@@ -0,0 +1,84 @@
+use super::Xyz;
+
+/// Linear light RGB color with ITU-R BT.2020 primaries and D65 white point.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Rec2020 {
+    red: f64,
+    green: f64,
+    blue: f64,
+}
+
+impl From<Xyz> for Rec2020 {
+    fn from(xyz: Xyz) -> Self {
+        let (x, y, z) = xyz.into_tuple();
+
+        Self {
+            red: 1.7166511880 * x - 0.3556707838 * y - 0.2533662814 * z,
+            green: -0.6666843518 * x + 1.6164812366 * y + 0.0157685458 * z,
+            blue: 0.0176398574 * x - 0.0427706133 * y + 0.9421031212 * z,
+        }
+    }
+}
+
+impl From<Rec2020> for Xyz {
+    fn from(rgb: Rec2020) -> Self {
+        let Rec2020 { red, green, blue } = rgb;
+
+        Xyz::from((
+            0.6369580483 * red + 0.1446169036 * green + 0.1688809752 * blue,
+            0.2627002120 * red + 0.6779980715 * green + 0.0593017165 * blue,
+            0.0280726930 * green + 1.0609850577 * blue,
+        ))
+    }
+}
+
+impl From<(f64, f64, f64)> for Rec2020 {
+    fn from((red, green, blue): (f64, f64, f64)) -> Self {
+        Self { red, green, blue }
+    }
+}
+
+impl Rec2020 {
+    pub fn into_tuple(self) -> (f64, f64, f64) {
+        (self.red, self.green, self.blue)
+    }
+
+    /// Relative luminance of the color.
+    pub fn luminance(self) -> f64 {
+        0.2627002120 * self.red + 0.6779980715 * self.green + 0.0593017165 * self.blue
+    }
+}
+
+getters!(Rec2020 {
+    /// Linear red channel.
+    red,
+    /// Linear green channel.
+    green,
+    /// Linear blue channel.
+    blue,
+});
+
+#[cfg(test)]
+mod tests {
+    use crate::format::{Rgb, Xyz};
+
+    use super::Rec2020;
+
+    #[test]
+    fn white_from_xyz() {
+        let (r, g, b) = Rec2020::from(Xyz::from(Rgb::from((255, 255, 255)))).into_tuple();
+
+        assert!((r - 1.0).abs() < 1e-4);
+        assert!((g - 1.0).abs() < 1e-4);
+        assert!((b - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn srgb_red_inside_rec2020() {
+        let (r, g, b) = Rec2020::from(Xyz::from(Rgb::from((255, 0, 0)))).into_tuple();
+
+        assert!((r - 0.6274).abs() < 1e-3);
+        assert!((g - 0.0691).abs() < 1e-3);
+        assert!((b - 0.0164).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,113 @@
+//! Table of color spaces of this crate, used where colors are converted
+//! without knowing the type of their space, e.g. by [`Color::convert`] and
+//! [`Graph`](super::Graph).
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{error::ConversionError, Color, Format};
+
+use super::{
+    ColorSpace, Component, Hlg, Hsl, Hsv, Ictcp, Jzazbz, Jzczhz, Lab, Lch, Oklab, Oklch, Pq, Rgb,
+    Rgb48, Scrgb, Xyy, Xyz,
+};
+
+/// Color space with its type erased, see [`ColorSpace`]. Colors are passed
+/// around as components, see [`ColorSpace::components`].
+#[derive(Debug, Clone, Copy)]
+// Conversions between spaces are only used by `Graph`, which needs `std`.
+#[cfg_attr(not(feature = "std"), allow(dead_code))]
+pub(crate) struct Registration {
+    pub(crate) name: &'static str,
+    pub(crate) parent: &'static str,
+    pub(crate) components: &'static [Component],
+    pub(crate) to_parent: fn(&[f64]) -> Vec<f64>,
+    pub(crate) from_parent: fn(&[f64]) -> Vec<f64>,
+    pub(crate) from_color: fn(Color) -> Vec<f64>,
+    pub(crate) parse: fn(&[String]) -> Result<Vec<f64>, ConversionError>,
+    pub(crate) serialize: fn(&[f64], Option<usize>) -> String,
+    /// Format written from the space without any options, if any.
+    pub(crate) format: Option<Format>,
+}
+
+impl Registration {
+    pub(crate) const fn of<S: ColorSpace>(format: Option<Format>) -> Self {
+        Self {
+            name: S::NAME,
+            parent: S::Parent::NAME,
+            components: S::COMPONENTS,
+            to_parent: |c| S::from_components(c).to_parent().components(),
+            from_parent: |c| S::from_parent(S::Parent::from_components(c)).components(),
+            from_color: |color| color.to_space::<S>().components(),
+            parse: |values| S::parse(values).map(|color| color.components()),
+            serialize: |c, precision| S::from_components(c).serialize(precision),
+            format,
+        }
+    }
+
+    /// Writes color converted into the space.
+    pub(crate) fn write(&self, color: Color, precision: Option<usize>) -> String {
+        (self.serialize)(&(self.from_color)(color), precision)
+    }
+}
+
+/// All spaces of this crate. Spaces of formats with options, e.g. peak
+/// luminance of [`Pq`], are written from [`Color::convert_with`] instead.
+pub(crate) const BUILTIN: [Registration; 16] = [
+    Registration::of::<Rgb>(Some(Format::Rgb)),
+    Registration::of::<Rgb48>(Some(Format::Rgb48)),
+    Registration::of::<Hsl>(Some(Format::Hsl)),
+    Registration::of::<Hsv>(Some(Format::Hsv)),
+    Registration::of::<Scrgb>(Some(Format::Scrgb)),
+    Registration::of::<Xyz>(None),
+    Registration::of::<Xyy>(None),
+    Registration::of::<Lab>(None),
+    Registration::of::<Lch>(None),
+    Registration::of::<Oklab>(Some(Format::Oklab)),
+    Registration::of::<Oklch>(Some(Format::Oklch)),
+    Registration::of::<Pq>(None),
+    Registration::of::<Hlg>(None),
+    Registration::of::<Ictcp>(None),
+    Registration::of::<Jzazbz>(None),
+    Registration::of::<Jzczhz>(None),
+];
+
+/// Returns space written by given format without any options.
+pub(crate) fn of_format(format: Format) -> Option<&'static Registration> {
+    BUILTIN
+        .iter()
+        .find(|registration| registration.format == Some(format))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::{
+        format::{Hsl, Oklch},
+        Color, Format,
+    };
+
+    use super::{of_format, BUILTIN};
+
+    #[test]
+    fn formats() {
+        let color = Color::from_hex_u32(0x3366cc);
+
+        assert_eq!(
+            color.convert(Format::Hsl).unwrap(),
+            Hsl::from(color).to_string()
+        );
+        assert_eq!(
+            color.convert(Format::Oklch).unwrap(),
+            Oklch::from(color).to_string()
+        );
+        assert_eq!(Format::Oklch.space(), Some("oklch"));
+        assert!(of_format(Format::Pq).is_none());
+
+        for (index, registration) in BUILTIN.iter().enumerate() {
+            assert!(BUILTIN[..index]
+                .iter()
+                .all(|other| other.name != registration.name));
+        }
+    }
+}
@@ -0,0 +1,350 @@
+use alloc::{format, string::String, vec, vec::Vec};
+
+use super::{check_range, component, decimal, hsl::Hsl, hsv::Hsv, ColorSpace, Component};
+use crate::{error::ConversionError, Color};
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+
+/// RGB color with channels stored as floating point numbers in range 0..1.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Rgb {
+    red: f64,
+    green: f64,
+    blue: f64,
+}
+
+impl From<Color> for Rgb {
+    fn from(color: Color) -> Self {
+        Self {
+            red: color.red,
+            green: color.green,
+            blue: color.blue,
+        }
+    }
+}
+
+impl From<(u8, u8, u8)> for Rgb {
+    fn from((red, green, blue): (u8, u8, u8)) -> Self {
+        Self {
+            red: red as f64 / 255.0,
+            green: green as f64 / 255.0,
+            blue: blue as f64 / 255.0,
+        }
+    }
+}
+
+impl From<(f64, f64, f64)> for Rgb {
+    fn from((red, green, blue): (f64, f64, f64)) -> Self {
+        Self { red, green, blue }
+    }
+}
+
+impl From<Hsl> for Rgb {
+    fn from(value: Hsl) -> Self {
+        let (h, s, l) = value.into_tuple();
+        let h = h.rem_euclid(360.0);
+
+        let s = s / 100.0;
+        let l = l / 100.0;
+
+        let c: f64 = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+        let m = l - c / 2.0;
+
+        let (r1, g1, b1) = from_hue_sector(h, c, x);
+
+        Self {
+            red: r1 + m,
+            green: g1 + m,
+            blue: b1 + m,
+        }
+    }
+}
+
+impl From<Hsv> for Rgb {
+    fn from(value: Hsv) -> Self {
+        let (h, s, v) = value.into_tuple();
+        let h = h.rem_euclid(360.0);
+
+        let s = s / 100.0;
+        let v = v / 100.0;
+
+        let c: f64 = v * s;
+        let x = c * (1.0 - ((h / 60.0) % 2.0 - 1.0).abs());
+        let m = v - c;
+
+        let (r1, g1, b1) = from_hue_sector(h, c, x);
+
+        Self {
+            red: r1 + m,
+            green: g1 + m,
+            blue: b1 + m,
+        }
+    }
+}
+
+/// Distributes chroma `c` and second largest component `x` between channels
+/// depending on which 60° sector of the color wheel the hue falls into.
+///
+/// Hue is expected in range 0..360, but may be rounded up to 360 when
+/// normalized, e.g. `(-1e-20).rem_euclid(360.0)`, which falls into the last
+/// sector.
+fn from_hue_sector(h: f64, c: f64, x: f64) -> (f64, f64, f64) {
+    match ((h / 60.0) as u8).min(5) {
+        0 => (c, x, 0.0),
+        1 => (x, c, 0.0),
+        2 => (0.0, c, x),
+        3 => (0.0, x, c),
+        4 => (x, 0.0, c),
+        _ => (c, 0.0, x),
+    }
+}
+
+impl ColorSpace for Rgb {
+    const NAME: &'static str = "rgb";
+    const COMPONENTS: &'static [Component] = &[
+        Component::new("red", 0.0, 1.0),
+        Component::new("green", 0.0, 1.0),
+        Component::new("blue", 0.0, 1.0),
+    ];
+
+    type Parent = Rgb;
+
+    fn to_parent(&self) -> Rgb {
+        *self
+    }
+
+    fn from_parent(parent: Rgb) -> Self {
+        parent
+    }
+
+    fn components(&self) -> Vec<f64> {
+        vec![self.red, self.green, self.blue]
+    }
+
+    fn from_components(c: &[f64]) -> Self {
+        Rgb::from((component(c, 0), component(c, 1), component(c, 2)))
+    }
+
+    fn parse(values: &[String]) -> Result<Self, ConversionError> {
+        Color::try_from(values).map(Rgb::from)
+    }
+
+    fn try_from_components(components: &[f64]) -> Result<Self, ConversionError> {
+        let [red, green, blue] = components else {
+            return Err(ConversionError::WrongCount {
+                expected: vec![3],
+                found: components.len(),
+            });
+        };
+
+        Ok(Self {
+            red: check_range(*red, "red", 0.0..=1.0)?,
+            green: check_range(*green, "green", 0.0..=1.0)?,
+            blue: check_range(*blue, "blue", 0.0..=1.0)?,
+        })
+    }
+
+    fn to_hub(&self) -> Rgb {
+        *self
+    }
+
+    fn from_hub(rgb: Rgb) -> Self {
+        rgb
+    }
+}
+
+impl core::fmt::Display for Rgb {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let precision = f.precision().unwrap_or(0);
+        let [red, green, blue] =
+            [self.red, self.green, self.blue].map(|c| decimal(c * 255.0, precision));
+
+        f.write_fmt(format_args!("rgb({red}, {green}, {blue})"))
+    }
+}
+
+impl Rgb {
+    pub fn to_hex(self) -> String {
+        let (red, green, blue) = self.to_bytes();
+
+        format!("{red:02x}{green:02x}{blue:02x}")
+    }
+
+    pub fn into_tuple(self) -> (f64, f64, f64) {
+        (self.red, self.green, self.blue)
+    }
+
+    /// Creates color from linear light channels by applying the sRGB transfer
+    /// function.
+    pub fn from_linear((red, green, blue): (f64, f64, f64)) -> Self {
+        Self {
+            red: from_linear(red),
+            green: from_linear(green),
+            blue: from_linear(blue),
+        }
+    }
+
+    /// Removes the sRGB transfer function, returning linear light channels.
+    pub fn to_linear(self) -> (f64, f64, f64) {
+        (
+            to_linear(self.red),
+            to_linear(self.green),
+            to_linear(self.blue),
+        )
+    }
+
+    /// Clamps channels to range 0..1.
+    pub fn clamped(self) -> Self {
+        Self {
+            red: self.red.clamp(0.0, 1.0),
+            green: self.green.clamp(0.0, 1.0),
+            blue: self.blue.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Rounds channels to 8 bit integers in range 0..255.
+    pub fn to_bytes(self) -> (u8, u8, u8) {
+        (to_byte(self.red), to_byte(self.green), to_byte(self.blue))
+    }
+}
+
+/// Removes the sRGB transfer function from gamma encoded channel value.
+/// Values outside of range 0..1 are extended by mirroring the curve around
+/// zero, as in scRGB.
+pub(crate) fn to_linear(c: f64) -> f64 {
+    let abs = c.abs();
+
+    let linear = if abs <= 0.04045 {
+        abs / 12.92
+    } else {
+        ((abs + 0.055) / 1.055).powf(2.4)
+    };
+
+    linear.copysign(c)
+}
+
+/// Applies the sRGB transfer function to linear channel value. Values outside
+/// of range 0..1 are extended by mirroring the curve around zero, as in scRGB.
+pub(crate) fn from_linear(c: f64) -> f64 {
+    let abs = c.abs();
+
+    let encoded = if abs <= 0.0031308 {
+        abs * 12.92
+    } else {
+        1.055 * abs.powf(1.0 / 2.4) - 0.055
+    };
+
+    encoded.copysign(c)
+}
+
+/// Rounds channel value in range 0..1 to 8 bit integer.
+pub(crate) fn to_byte(channel: f64) -> u8 {
+    (channel.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Rounds channel value in range 0..1 to 16 bit integer.
+pub(crate) fn to_word(channel: f64) -> u16 {
+    (channel.clamp(0.0, 1.0) * 65535.0).round() as u16
+}
+
+getters!(Rgb {
+    /// Red channel in range 0..1.
+    red,
+    /// Green channel in range 0..1.
+    green,
+    /// Blue channel in range 0..1.
+    blue,
+});
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString, vec::Vec};
+
+    use crate::format::{hsl::Hsl, hsv::Hsv};
+
+    use super::Rgb;
+
+    #[test]
+    fn hue_outside_of_range() {
+        // Normalizing tiny negative hue gives exactly 360.
+        let hue = (-1e-20f64).rem_euclid(360.0);
+        assert_eq!(hue, 360.0);
+        assert_eq!(super::from_hue_sector(hue, 1.0, 0.0), (1.0, 0.0, 0.0));
+
+        let rgb = Rgb::from(Hsv::from((-1e-20, 100.0, 100.0)));
+        assert_eq!(rgb.to_bytes(), (255, 0, 0));
+
+        let rgb = Rgb::from(Hsl::from((480.0, 100.0, 50.0)));
+        assert_eq!(rgb.to_bytes(), (0, 255, 0));
+
+        // Saturation and value out of range give colors outside of gamut.
+        let rgb = Rgb::from(Hsv::from((400.0, 150.0, 50.0)));
+        assert!(rgb.blue < 0.0);
+    }
+
+    #[test]
+    fn black_from_hsl() {
+        let hsl = Hsl::from((0.0, 0.0, 0.0));
+
+        let rgb = Rgb::from(hsl);
+
+        assert_eq!(rgb.to_bytes(), (0, 0, 0))
+    }
+
+    #[test]
+    fn white_from_hsl() {
+        let hsl = Hsl::from((0.0, 0.0, 100.0));
+
+        let rgb = Rgb::from(hsl);
+
+        assert_eq!(rgb.to_bytes(), (255, 255, 255))
+    }
+
+    #[test]
+    fn red_from_hsl() {
+        let hsl = Hsl::from((0.0, 100.0, 50.0));
+
+        let rgb = Rgb::from(hsl);
+
+        assert_eq!(rgb.to_bytes(), (255, 0, 0))
+    }
+
+    #[test]
+    fn olive_from_hsl() {
+        let hsl = Hsl::from((60.0, 100.0, 25.0));
+
+        let rgb = Rgb::from(hsl);
+
+        assert_eq!(rgb.to_bytes(), (128, 128, 0))
+    }
+
+    #[test]
+    fn display_precision() {
+        let rgb = Rgb::from(Hsl::from((60.0, 100.0, 25.0)));
+
+        assert_eq!(rgb.to_string(), "rgb(128, 128, 0)");
+        assert_eq!(format!("{rgb:.1}"), "rgb(127.5, 127.5, 0.0)");
+    }
+
+    #[test]
+    fn extended_linear_roundtrip() {
+        let linear = (1.8, 0.2, -0.05);
+        let (r, g, b) = Rgb::from_linear(linear).to_linear();
+
+        assert!((r - 1.8).abs() < 1e-12);
+        assert!((g - 0.2).abs() < 1e-12);
+        assert!((b + 0.05).abs() < 1e-12);
+    }
+
+    #[test]
+    fn color_from_chars() {
+        let values = ["51", "102", "204"].map(|value| value.chars().collect::<Vec<_>>());
+
+        let color = crate::Color::try_from(&values[..]).unwrap();
+        assert_eq!(Rgb::from(color).to_bytes(), (51, 102, 204));
+
+        assert!(crate::Color::try_from(&values[..2]).is_err());
+    }
+}
@@ -0,0 +1,165 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::{error::ConversionError, Color};
+
+use super::{
+    check_count, check_range, component, parse_component, to_word, ColorSpace, Component, Rgb,
+};
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+
+/// RGB color with 16 bit integer channels in range 0..65535, e.g.
+/// `rgb48(13107, 26214, 52428)`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Rgb48 {
+    rgb: Rgb,
+}
+
+impl From<Rgb> for Rgb48 {
+    fn from(rgb: Rgb) -> Self {
+        Self { rgb }
+    }
+}
+
+impl From<Rgb48> for Color {
+    fn from(rgb48: Rgb48) -> Self {
+        Color::from(rgb48.rgb)
+    }
+}
+
+impl From<(u16, u16, u16)> for Rgb48 {
+    fn from((red, green, blue): (u16, u16, u16)) -> Self {
+        let channel = |c: u16| c as f64 / u16::MAX as f64;
+
+        Self {
+            rgb: Rgb::from((channel(red), channel(green), channel(blue))),
+        }
+    }
+}
+
+impl Rgb48 {
+    /// Returns red, green and blue channels in range 0..65535.
+    pub fn into_tuple(self) -> (u16, u16, u16) {
+        let (red, green, blue) = self.rgb.into_tuple();
+
+        (to_word(red), to_word(green), to_word(blue))
+    }
+}
+
+impl TryFrom<&[String]> for Rgb48 {
+    type Error = ConversionError;
+
+    fn try_from(value: &[String]) -> Result<Self, Self::Error> {
+        check_count(value, &[3])?;
+
+        let mut channels = [0f64; 3];
+
+        for ((channel, value), name) in channels.iter_mut().zip(value).zip(["red", "green", "blue"])
+        {
+            let word = check_range(parse_component(value, name)?, name, 0.0..=u16::MAX as f64)?;
+
+            if word.fract() != 0.0 {
+                return Err(ConversionError::Parse {
+                    component: name,
+                    value: value.clone(),
+                });
+            }
+
+            *channel = word / u16::MAX as f64;
+        }
+
+        let [red, green, blue] = channels;
+
+        Ok(Self {
+            rgb: Rgb::from((red, green, blue)),
+        })
+    }
+}
+
+impl ColorSpace for Rgb48 {
+    const NAME: &'static str = "rgb48";
+    const COMPONENTS: &'static [Component] = &[
+        Component::new("red", 0.0, 65535.0),
+        Component::new("green", 0.0, 65535.0),
+        Component::new("blue", 0.0, 65535.0),
+    ];
+
+    type Parent = Rgb;
+
+    fn to_parent(&self) -> Rgb {
+        self.rgb
+    }
+
+    fn from_parent(rgb: Rgb) -> Self {
+        Self { rgb }
+    }
+
+    fn components(&self) -> Vec<f64> {
+        let (red, green, blue) = self.rgb.into_tuple();
+
+        [red, green, blue].map(|c| c * u16::MAX as f64).to_vec()
+    }
+
+    fn from_components(c: &[f64]) -> Self {
+        let channel = |index| component(c, index) / u16::MAX as f64;
+
+        Self {
+            rgb: Rgb::from((channel(0), channel(1), channel(2))),
+        }
+    }
+
+    fn parse(values: &[String]) -> Result<Self, ConversionError> {
+        Self::try_from(values)
+    }
+}
+
+impl core::fmt::Display for Rgb48 {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let (red, green, blue) = self.rgb.into_tuple();
+
+        f.write_fmt(format_args!(
+            "rgb48({}, {}, {})",
+            to_word(red),
+            to_word(green),
+            to_word(blue)
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+
+    use crate::format::{rgb48::Rgb48, Rgb};
+
+    #[test]
+    fn from_rgb() {
+        assert_eq!(
+            Rgb48::from(Rgb::from((51, 102, 204))).to_string(),
+            "rgb48(13107, 26214, 52428)"
+        );
+    }
+
+    #[test]
+    fn keeps_precision() {
+        let values = ["1", "32768", "65534"].map(String::from);
+        let rgb48 = Rgb48::try_from(&values[..]).unwrap();
+
+        assert_eq!(rgb48.to_string(), "rgb48(1, 32768, 65534)");
+    }
+
+    #[test]
+    fn tuple_roundtrip() {
+        let rgb48 = Rgb48::from((1, 32768, 65535));
+
+        assert_eq!(rgb48.into_tuple(), (1, 32768, 65535));
+    }
+
+    #[test]
+    fn out_of_range() {
+        let values = ["0", "65536", "0"].map(String::from);
+
+        assert!(Rgb48::try_from(&values[..]).is_err());
+    }
+}
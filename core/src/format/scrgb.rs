@@ -0,0 +1,142 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::{error::ConversionError, Color};
+
+use super::{component, decimal, parse_component, ColorSpace, Component, Rgb};
+
+/// scRGB color: linear light sRGB with unbounded channels, as used by HDR
+/// user interfaces, e.g. `scrgb(1.8, 0.2, -0.05)`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Scrgb {
+    red: f64,
+    green: f64,
+    blue: f64,
+}
+
+impl From<Rgb> for Scrgb {
+    fn from(rgb: Rgb) -> Self {
+        let (red, green, blue) = rgb.to_linear();
+
+        Self { red, green, blue }
+    }
+}
+
+impl From<Scrgb> for Color {
+    fn from(scrgb: Scrgb) -> Self {
+        Color::from(Rgb::from_linear((scrgb.red, scrgb.green, scrgb.blue)))
+    }
+}
+
+impl From<(f64, f64, f64)> for Scrgb {
+    fn from((red, green, blue): (f64, f64, f64)) -> Self {
+        Self { red, green, blue }
+    }
+}
+
+getters!(Scrgb {
+    /// Linear red channel, 1 for reference white.
+    red,
+    /// Linear green channel, 1 for reference white.
+    green,
+    /// Linear blue channel, 1 for reference white.
+    blue,
+});
+
+impl TryFrom<&[String]> for Scrgb {
+    type Error = ConversionError;
+
+    fn try_from(value: &[String]) -> Result<Self, Self::Error> {
+        let [red, green, blue] = value else {
+            return Err(ConversionError::WrongCount {
+                expected: vec![3],
+                found: value.len(),
+            });
+        };
+
+        Ok(Self {
+            red: parse_component(red, "red")?,
+            green: parse_component(green, "green")?,
+            blue: parse_component(blue, "blue")?,
+        })
+    }
+}
+
+impl ColorSpace for Scrgb {
+    const NAME: &'static str = "scrgb";
+    const COMPONENTS: &'static [Component] = &[
+        Component::unbounded("red"),
+        Component::unbounded("green"),
+        Component::unbounded("blue"),
+    ];
+
+    type Parent = Rgb;
+
+    fn to_parent(&self) -> Rgb {
+        Rgb::from_linear((self.red, self.green, self.blue))
+    }
+
+    fn from_parent(rgb: Rgb) -> Self {
+        Self::from(rgb)
+    }
+
+    fn components(&self) -> Vec<f64> {
+        vec![self.red, self.green, self.blue]
+    }
+
+    fn from_components(c: &[f64]) -> Self {
+        Self::from((component(c, 0), component(c, 1), component(c, 2)))
+    }
+
+    fn parse(values: &[String]) -> Result<Self, ConversionError> {
+        Self::try_from(values)
+    }
+}
+
+impl core::fmt::Display for Scrgb {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let precision = f.precision().unwrap_or(4);
+
+        f.write_fmt(format_args!(
+            "scrgb({}, {}, {})",
+            decimal(self.red, precision),
+            decimal(self.green, precision),
+            decimal(self.blue, precision)
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+
+    use crate::{
+        format::{scrgb::Scrgb, Rgb},
+        Color,
+    };
+
+    #[test]
+    fn white_from_rgb() {
+        let scrgb = Scrgb::from(Rgb::from((255, 255, 255)));
+
+        assert_eq!(scrgb.to_string(), "scrgb(1.0000, 1.0000, 1.0000)");
+    }
+
+    #[test]
+    fn mid_gray_from_rgb() {
+        let scrgb = Scrgb::from(Rgb::from((128, 128, 128)));
+
+        assert_eq!(scrgb.to_string(), "scrgb(0.2159, 0.2159, 0.2159)");
+    }
+
+    #[test]
+    fn extended_range_roundtrip() {
+        let values = ["1.8", "0.2", "-0.05"].map(String::from);
+        let color = Color::from(Scrgb::try_from(&values[..]).unwrap());
+
+        assert!(!color.is_in_range());
+        assert_eq!(
+            Scrgb::from(Rgb::from(color)).to_string(),
+            "scrgb(1.8000, 0.2000, -0.0500)"
+        );
+    }
+}
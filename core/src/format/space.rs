@@ -0,0 +1,498 @@
+//! RGB color spaces defined by chromaticities of their primaries, white point
+//! and transfer function, e.g. measured displays or CSS predefined spaces.
+
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::{error::ConversionError, Adaptation, Color};
+
+use super::{
+    check_range, decimal,
+    matrix::{apply, diagonal, invert, multiply, xy_to_xyz, Matrix},
+    parse_component,
+    rgb::{from_linear, to_linear},
+    Rgb, Xyz, D65_CHROMATICITY,
+};
+
+#[cfg(not(any(feature = "std", test)))]
+use crate::math::Float;
+
+/// Transfer function encoding linear light channel values.
+///
+/// Channel values outside of range 0..1 are mirrored around 0, so that colors
+/// outside of the gamut of the space can be represented.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "lowercase"))]
+pub enum Transfer {
+    /// No encoding, channels are linear light.
+    Linear,
+
+    /// Piecewise sRGB transfer function.
+    Srgb,
+
+    /// Pure power function with given exponent, e.g. 2.2.
+    Gamma(f64),
+
+    /// Parametric curve, see [`Parametric`].
+    Parametric(Parametric),
+}
+
+/// Parametric transfer curve as used by ICC profiles (`parametricCurveType`
+/// with function type 4). Encoded value `x` is decoded to linear light `y` as:
+///
+/// - `y = (a * x + b)^g + e` for `x >= d`
+/// - `y = c * x + f` for `x < d`
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "std", derive(serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(deny_unknown_fields))]
+pub struct Parametric {
+    pub g: f64,
+    #[cfg_attr(feature = "std", serde(default = "one"))]
+    pub a: f64,
+    #[cfg_attr(feature = "std", serde(default))]
+    pub b: f64,
+    #[cfg_attr(feature = "std", serde(default))]
+    pub c: f64,
+    #[cfg_attr(feature = "std", serde(default))]
+    pub d: f64,
+    #[cfg_attr(feature = "std", serde(default))]
+    pub e: f64,
+    #[cfg_attr(feature = "std", serde(default))]
+    pub f: f64,
+}
+
+#[cfg(feature = "std")]
+fn one() -> f64 {
+    1.0
+}
+
+impl Transfer {
+    /// Converts encoded channel value to linear light.
+    pub fn decode(self, value: f64) -> f64 {
+        let abs = value.abs();
+
+        let linear = match self {
+            Transfer::Linear => abs,
+            Transfer::Srgb => to_linear(abs),
+            Transfer::Gamma(gamma) => abs.powf(gamma),
+            Transfer::Parametric(Parametric {
+                g,
+                a,
+                b,
+                c,
+                d,
+                e,
+                f,
+            }) => {
+                if abs >= d {
+                    (a * abs + b).max(0.0).powf(g) + e
+                } else {
+                    c * abs + f
+                }
+            }
+        };
+
+        linear.copysign(value)
+    }
+
+    /// Converts linear light channel value to encoded value.
+    pub fn encode(self, value: f64) -> f64 {
+        let abs = value.abs();
+
+        let encoded = match self {
+            Transfer::Linear => abs,
+            Transfer::Srgb => from_linear(abs),
+            Transfer::Gamma(gamma) => abs.powf(1.0 / gamma),
+            Transfer::Parametric(Parametric {
+                g,
+                a,
+                b,
+                c,
+                d,
+                e,
+                f,
+            }) => {
+                // Without the linear segment, decoding never gives values
+                // below its end.
+                if d > 0.0 && abs < c * d + f {
+                    (abs - f) / c
+                } else {
+                    ((abs - e).max(0.0).powf(1.0 / g) - b) / a
+                }
+            }
+        };
+
+        encoded.copysign(value)
+    }
+}
+
+/// RGB color space given by chromaticity coordinates of its primaries and
+/// white point, and by its transfer function.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RgbSpace {
+    name: String,
+    red: (f64, f64),
+    green: (f64, f64),
+    blue: (f64, f64),
+    white: (f64, f64),
+    transfer: Transfer,
+}
+
+impl RgbSpace {
+    /// Creates a new RGB space. Chromaticity coordinates are given as `(x, y)`
+    /// pairs.
+    pub fn new(
+        name: impl Into<String>,
+        [red, green, blue]: [(f64, f64); 3],
+        white: (f64, f64),
+        transfer: Transfer,
+    ) -> Result<Self, ConversionError> {
+        let name = name.into();
+
+        if name.is_empty() || !name.chars().all(is_name_char) {
+            return Err(ConversionError::InvalidName { name });
+        }
+
+        for (x, y) in [red, green, blue, white] {
+            check_range(x, "chromaticity x", 0.0..=1.0)?;
+            check_range(y, "chromaticity y", f64::MIN_POSITIVE..=1.0)?;
+        }
+
+        match transfer {
+            Transfer::Gamma(gamma) => {
+                check_range(gamma, "gamma", f64::MIN_POSITIVE..=f64::INFINITY)?;
+            }
+            Transfer::Parametric(Parametric { g, a, c, d, .. }) => {
+                check_range(g, "g", f64::MIN_POSITIVE..=f64::INFINITY)?;
+                check_range(a, "a", f64::MIN_POSITIVE..=f64::INFINITY)?;
+
+                // The linear segment is inverted when encoding.
+                if d > 0.0 {
+                    check_range(c, "c", f64::MIN_POSITIVE..=f64::INFINITY)?;
+                }
+            }
+            _ => {}
+        }
+
+        let space = Self {
+            name,
+            red,
+            green,
+            blue,
+            white,
+            transfer,
+        };
+
+        if invert(&space.primaries()).is_none() {
+            return Err(ConversionError::CollinearPrimaries);
+        }
+
+        Ok(space)
+    }
+
+    /// The sRGB color space.
+    pub fn srgb() -> Self {
+        Self {
+            name: String::from("srgb"),
+            red: (0.64, 0.33),
+            green: (0.30, 0.60),
+            blue: (0.15, 0.06),
+            white: D65_CHROMATICITY,
+            transfer: Transfer::Srgb,
+        }
+    }
+
+    /// Color spaces predefined by CSS Color Module Level 4, usable in
+    /// `color()` function without any configuration.
+    pub fn predefined() -> Vec<Self> {
+        let srgb = Self::srgb();
+        let rec2020_alpha = 1.09929682680944;
+        let rec2020_beta = 0.018053968510807;
+
+        let space = |name: &str, primaries, white, transfer| {
+            Self::new(name, primaries, white, transfer).expect("predefined space is valid")
+        };
+
+        vec![
+            space(
+                "srgb-linear",
+                [srgb.red, srgb.green, srgb.blue],
+                D65_CHROMATICITY,
+                Transfer::Linear,
+            ),
+            space(
+                "display-p3",
+                [(0.68, 0.32), (0.265, 0.69), (0.15, 0.06)],
+                D65_CHROMATICITY,
+                Transfer::Srgb,
+            ),
+            space(
+                "a98-rgb",
+                [(0.64, 0.33), (0.21, 0.71), (0.15, 0.06)],
+                D65_CHROMATICITY,
+                Transfer::Gamma(563.0 / 256.0),
+            ),
+            space(
+                "prophoto-rgb",
+                [
+                    (0.734699, 0.265301),
+                    (0.159597, 0.840403),
+                    (0.036598, 0.000105),
+                ],
+                (0.3457, 0.3585),
+                Transfer::Parametric(Parametric {
+                    g: 1.8,
+                    a: 1.0,
+                    b: 0.0,
+                    c: 1.0 / 16.0,
+                    d: 1.0 / 32.0,
+                    e: 0.0,
+                    f: 0.0,
+                }),
+            ),
+            space(
+                "rec2020",
+                [(0.708, 0.292), (0.17, 0.797), (0.131, 0.046)],
+                D65_CHROMATICITY,
+                Transfer::Parametric(Parametric {
+                    g: 1.0 / 0.45,
+                    a: 1.0 / rec2020_alpha,
+                    b: (rec2020_alpha - 1.0) / rec2020_alpha,
+                    c: 1.0 / 4.5,
+                    d: rec2020_beta * 4.5,
+                    e: 0.0,
+                    f: 0.0,
+                }),
+            ),
+            srgb,
+        ]
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns matrix with XYZ coordinates of the primaries in its columns.
+    fn primaries(&self) -> Matrix {
+        let (rx, ry, rz) = xy_to_xyz(self.red);
+        let (gx, gy, gz) = xy_to_xyz(self.green);
+        let (bx, by, bz) = xy_to_xyz(self.blue);
+
+        [[rx, gx, bx], [ry, gy, by], [rz, gz, bz]]
+    }
+
+    /// Returns matrix converting linear RGB to XYZ relative to D65, adapting
+    /// colors from the white point of the space with given transform.
+    fn to_xyz_matrix(&self, adaptation: Adaptation) -> Matrix {
+        let primaries = self.primaries();
+        let inverse = invert(&primaries).expect("primaries are validated");
+        let scale = diagonal(apply(&inverse, xy_to_xyz(self.white)));
+        let to_xyz = multiply(&primaries, &scale);
+
+        multiply(&adaptation.matrix(self.white, D65_CHROMATICITY), &to_xyz)
+    }
+}
+
+/// Returns `true` if the character may be used in name of a color space.
+pub(crate) fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '_'
+}
+
+/// Color in an [`RgbSpace`], formatted like CSS `color()` function, e.g.
+/// `color(display-p3 0.2000 0.4000 0.8000)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpaceRgb<'a> {
+    space: &'a RgbSpace,
+    red: f64,
+    green: f64,
+    blue: f64,
+    adaptation: Adaptation,
+}
+
+impl<'a> SpaceRgb<'a> {
+    pub fn new(space: &'a RgbSpace, rgb: Rgb, adaptation: Adaptation) -> Self {
+        let to_rgb = invert(&space.to_xyz_matrix(adaptation)).expect("primaries are validated");
+        let (r, g, b) = apply(&to_rgb, Xyz::from(rgb).into_tuple());
+        let encode = |c: f64| space.transfer.encode(c);
+
+        Self {
+            space,
+            red: encode(r),
+            green: encode(g),
+            blue: encode(b),
+            adaptation,
+        }
+    }
+
+    /// Creates color from encoded channel values, which may be outside of
+    /// range 0..1 for colors outside of the gamut of the space.
+    pub fn try_from_values(
+        space: &'a RgbSpace,
+        value: &[String],
+        adaptation: Adaptation,
+    ) -> Result<Self, ConversionError> {
+        let [red, green, blue] = value else {
+            return Err(ConversionError::WrongCount {
+                expected: vec![3],
+                found: value.len(),
+            });
+        };
+
+        Ok(Self {
+            space,
+            red: parse_component(red, "red")?,
+            green: parse_component(green, "green")?,
+            blue: parse_component(blue, "blue")?,
+            adaptation,
+        })
+    }
+}
+
+impl From<SpaceRgb<'_>> for Color {
+    fn from(color: SpaceRgb<'_>) -> Self {
+        let decode = |c: f64| color.space.transfer.decode(c);
+        let linear = (decode(color.red), decode(color.green), decode(color.blue));
+        let xyz = apply(&color.space.to_xyz_matrix(color.adaptation), linear);
+
+        Color::from(Xyz::from(xyz).to_rgb())
+    }
+}
+
+impl core::fmt::Display for SpaceRgb<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let precision = f.precision().unwrap_or(4);
+
+        f.write_fmt(format_args!(
+            "color({} {} {} {})",
+            self.space.name,
+            decimal(self.red, precision),
+            decimal(self.green, precision),
+            decimal(self.blue, precision)
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString};
+
+    use crate::{error::ConversionError, format::Rgb, Adaptation, Color};
+
+    use super::{Parametric, RgbSpace, SpaceRgb, Transfer};
+
+    fn predefined(name: &str) -> RgbSpace {
+        RgbSpace::predefined()
+            .into_iter()
+            .find(|space| space.name() == name)
+            .unwrap()
+    }
+
+    #[test]
+    fn srgb() {
+        let space = RgbSpace::srgb();
+        let color = SpaceRgb::new(&space, Rgb::from((51, 102, 204)), Adaptation::Bradford);
+
+        assert_eq!(color.to_string(), "color(srgb 0.2000 0.4000 0.8000)");
+        assert_eq!(Rgb::from(Color::from(color)).to_bytes(), (51, 102, 204));
+    }
+
+    #[test]
+    fn display_p3() {
+        // Reference values from CSS Color Module Level 4.
+        let space = predefined("display-p3");
+        let red = SpaceRgb::new(&space, Rgb::from((255, 0, 0)), Adaptation::Bradford);
+
+        assert_eq!(format!("{red:.3}"), "color(display-p3 0.917 0.200 0.139)");
+    }
+
+    #[test]
+    fn prophoto_is_adapted_from_d50() {
+        // White is preserved when adapting between white points.
+        let space = predefined("prophoto-rgb");
+        let white = SpaceRgb::new(&space, Rgb::from((255, 255, 255)), Adaptation::Bradford);
+
+        assert_eq!(
+            white.to_string(),
+            "color(prophoto-rgb 1.0000 1.0000 1.0000)"
+        );
+    }
+
+    #[test]
+    fn transfer_roundtrip() {
+        let transfers = [
+            Transfer::Linear,
+            Transfer::Srgb,
+            Transfer::Gamma(2.2),
+            predefined("rec2020").transfer,
+            Transfer::Parametric(Parametric {
+                g: 2.4,
+                a: 1.0 / 1.055,
+                b: 0.055 / 1.055,
+                c: 1.0 / 12.92,
+                d: 0.04045,
+                e: 0.0,
+                f: 0.0,
+            }),
+            Transfer::Parametric(Parametric {
+                g: 2.2,
+                a: 1.0,
+                b: 0.0,
+                c: 0.0,
+                d: 0.0,
+                e: 0.0,
+                f: 0.1,
+            }),
+        ];
+
+        for transfer in transfers {
+            for value in [-0.5, 0.001, 0.02, 0.5, 1.0, 1.5] {
+                let roundtrip = transfer.encode(transfer.decode(value));
+                assert!((roundtrip - value).abs() < 1e-9, "{transfer:?}: {value}");
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_space() {
+        let collinear = [(0.1, 0.1), (0.2, 0.2), (0.3, 0.3)];
+
+        assert_eq!(
+            RgbSpace::new("line", collinear, (0.3127, 0.329), Transfer::Linear),
+            Err(ConversionError::CollinearPrimaries)
+        );
+        assert!(RgbSpace::new(
+            "my space",
+            [(0.64, 0.33), (0.3, 0.6), (0.15, 0.06)],
+            (0.3127, 0.329),
+            Transfer::Linear
+        )
+        .is_err());
+
+        let error = RgbSpace::new(
+            "dark",
+            [(0.64, 0.33), (0.3, 0.6), (0.15, 0.06)],
+            (0.3127, 0.329),
+            Transfer::Gamma(0.0),
+        )
+        .unwrap_err();
+        assert_eq!(error.to_string(), "gamma must be greater than 0, found 0");
+
+        let flat = Parametric {
+            g: 2.4,
+            a: 1.0,
+            b: 0.0,
+            c: 0.0,
+            d: 0.04,
+            e: 0.0,
+            f: 0.0,
+        };
+        let error = RgbSpace::new(
+            "flat",
+            [(0.64, 0.33), (0.3, 0.6), (0.15, 0.06)],
+            (0.3127, 0.329),
+            Transfer::Parametric(flat),
+        )
+        .unwrap_err();
+        assert_eq!(error.to_string(), "c must be greater than 0, found 0");
+    }
+}
@@ -0,0 +1,221 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::{error::ConversionError, Color};
+
+use super::{
+    check_range, component, decimal, parse_component, ColorSpace, Component, Rgb, Xyz,
+    D65_CHROMATICITY,
+};
+
+/// CIE xyY color: `x` and `y` chromaticity coordinates and `Y` luminance,
+/// where reference white has luminance of 1.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Xyy {
+    x: f64,
+    y: f64,
+    luminance: f64,
+}
+
+impl From<Xyz> for Xyy {
+    fn from(xyz: Xyz) -> Self {
+        Xyy::relative_to(xyz, D65_CHROMATICITY)
+    }
+}
+
+impl Xyy {
+    /// Converts XYZ color relative to white point with given chromaticity.
+    pub fn relative_to(xyz: Xyz, white: (f64, f64)) -> Self {
+        let (x, y, z) = xyz.into_tuple();
+        let sum = x + y + z;
+
+        // Chromaticity of black is undefined, use the chromaticity of the white
+        // point instead of dividing by zero.
+        let (cx, cy) = if sum == 0.0 {
+            white
+        } else {
+            (x / sum, y / sum)
+        };
+
+        Self {
+            x: cx,
+            y: cy,
+            luminance: y,
+        }
+    }
+}
+
+impl From<Xyy> for Xyz {
+    fn from(xyy: Xyy) -> Self {
+        let Xyy { x, y, luminance } = xyy;
+
+        // Chromaticity y of 0 is rejected by the parser, but components may
+        // also come e.g. from deserialization, such colors are black.
+        if luminance == 0.0 || y <= 0.0 {
+            return Xyz::from((0.0, 0.0, 0.0));
+        }
+
+        Xyz::from((x * luminance / y, luminance, (1.0 - x - y) * luminance / y))
+    }
+}
+
+impl From<Rgb> for Xyy {
+    fn from(rgb: Rgb) -> Self {
+        Xyy::from(Xyz::from(rgb))
+    }
+}
+
+impl From<Xyy> for Color {
+    fn from(xyy: Xyy) -> Self {
+        Color::from(Xyz::from(xyy).to_rgb())
+    }
+}
+
+impl TryFrom<(f64, f64, f64)> for Xyy {
+    type Error = ConversionError;
+
+    fn try_from((x, y, luminance): (f64, f64, f64)) -> Result<Self, Self::Error> {
+        check_range(x + y, "x + y", 0.0..=1.0)?;
+
+        Ok(Self {
+            x: check_range(x, "x", 0.0..=1.0)?,
+            y: check_range(y, "y", f64::MIN_POSITIVE..=1.0)?,
+            luminance: check_range(luminance, "Y", 0.0..=f64::INFINITY)?,
+        })
+    }
+}
+
+impl TryFrom<&[String]> for Xyy {
+    type Error = ConversionError;
+
+    fn try_from(value: &[String]) -> Result<Self, Self::Error> {
+        let [x, y, luminance] = value else {
+            return Err(ConversionError::WrongCount {
+                expected: vec![3],
+                found: value.len(),
+            });
+        };
+
+        Xyy::try_from((
+            parse_component(x, "x")?,
+            parse_component(y, "y")?,
+            parse_component(luminance, "Y")?,
+        ))
+    }
+}
+
+impl ColorSpace for Xyy {
+    const NAME: &'static str = "xyy";
+    const COMPONENTS: &'static [Component] = &[
+        Component::new("x", 0.0, 1.0),
+        Component::new("y", 0.0, 1.0),
+        Component::unbounded("luminance"),
+    ];
+
+    type Parent = Xyz;
+
+    fn to_parent(&self) -> Xyz {
+        Xyz::from(*self)
+    }
+
+    fn from_parent(xyz: Xyz) -> Self {
+        Self::from(xyz)
+    }
+
+    fn components(&self) -> Vec<f64> {
+        vec![self.x, self.y, self.luminance]
+    }
+
+    fn from_components(c: &[f64]) -> Self {
+        Self {
+            x: component(c, 0),
+            y: component(c, 1),
+            luminance: component(c, 2),
+        }
+    }
+
+    fn parse(values: &[String]) -> Result<Self, ConversionError> {
+        Self::try_from(values)
+    }
+}
+
+impl core::fmt::Display for Xyy {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let precision = f.precision().unwrap_or(4);
+
+        f.write_fmt(format_args!(
+            "xyy({}, {}, {})",
+            decimal(self.x, precision),
+            decimal(self.y, precision),
+            decimal(self.luminance, precision)
+        ))
+    }
+}
+
+getters!(Xyy {
+    /// Chromaticity coordinate x.
+    x,
+    /// Chromaticity coordinate y.
+    y,
+    /// Luminance Y, where reference white has luminance of 1.
+    luminance,
+});
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use crate::{
+        format::{xyy::Xyy, ColorSpace, Rgb, Xyz},
+        Color,
+    };
+
+    #[test]
+    fn white_from_rgb() {
+        let xyy = Xyy::from(Rgb::from((255, 255, 255)));
+
+        assert_eq!(xyy.to_string(), "xyy(0.3127, 0.3290, 1.0000)");
+    }
+
+    #[test]
+    fn black_from_rgb() {
+        let xyy = Xyy::from(Rgb::from((0, 0, 0)));
+
+        assert_eq!(xyy.to_string(), "xyy(0.3127, 0.3290, 0.0000)");
+    }
+
+    #[test]
+    fn red_from_rgb() {
+        let xyy = Xyy::from(Rgb::from((255, 0, 0)));
+
+        assert_eq!(xyy.to_string(), "xyy(0.6400, 0.3300, 0.2126)");
+    }
+
+    #[test]
+    fn white_to_color() {
+        let xyy = Xyy::try_from((0.3127, 0.3290, 1.0)).unwrap();
+
+        assert_eq!(Rgb::from(Color::from(xyy)).to_bytes(), (255, 255, 255));
+    }
+
+    #[test]
+    fn zero_luminance_is_black() {
+        let xyy = Xyy::try_from((0.64, 0.33, 0.0)).unwrap();
+
+        assert_eq!(Rgb::from(Color::from(xyy)).to_bytes(), (0, 0, 0));
+    }
+
+    #[test]
+    fn zero_y_chromaticity_is_error() {
+        assert!(Xyy::try_from((0.3, 0.0, 0.5)).is_err());
+        assert!(Xyy::try_from((0.3, 0.0, 0.0)).is_err());
+
+        let error = Xyy::try_from((0.3, 0.0, 0.5)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "y must be greater than 0 and at most 1, found 0"
+        );
+
+        let xyz = Xyz::from(Xyy::from_components(&[0.3, 0.0, 0.5]));
+        assert_eq!(xyz, Xyz::from((0.0, 0.0, 0.0)));
+    }
+}
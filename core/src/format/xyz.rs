@@ -0,0 +1,159 @@
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::{error::ConversionError, Color};
+
+use super::{check_range, component, decimal, parse_component, ColorSpace, Component, Rgb};
+
+/// CIE 1931 XYZ tristimulus values relative to the D65 white point, with `Y`
+/// normalized so that reference white has luminance of 1.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Xyz {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+/// Chromaticity coordinates of the D65 white point.
+pub const D65_CHROMATICITY: (f64, f64) = (0.3127, 0.3290);
+
+impl From<Rgb> for Xyz {
+    fn from(rgb: Rgb) -> Self {
+        let (r, g, b) = rgb.to_linear();
+
+        Self {
+            x: 0.4123907993 * r + 0.3575843394 * g + 0.1804807884 * b,
+            y: 0.2126390059 * r + 0.7151686788 * g + 0.0721923154 * b,
+            z: 0.0193308187 * r + 0.1191947798 * g + 0.9505321522 * b,
+        }
+    }
+}
+
+impl From<(f64, f64, f64)> for Xyz {
+    fn from((x, y, z): (f64, f64, f64)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl Xyz {
+    pub fn into_tuple(self) -> (f64, f64, f64) {
+        (self.x, self.y, self.z)
+    }
+
+    /// Converts to sRGB. Colors outside of the sRGB gamut have channels outside
+    /// of range 0..1.
+    pub fn to_rgb(self) -> Rgb {
+        let Self { x, y, z } = self;
+
+        let r = 3.2409699419 * x - 1.5373831776 * y - 0.4986107603 * z;
+        let g = -0.9692436363 * x + 1.8759675015 * y + 0.0415550574 * z;
+        let b = 0.0556300797 * x - 0.2039769589 * y + 1.0569715142 * z;
+
+        Rgb::from_linear((r, g, b))
+    }
+}
+
+impl From<Xyz> for Color {
+    fn from(xyz: Xyz) -> Self {
+        Color::from(xyz.to_rgb())
+    }
+}
+
+impl TryFrom<&[String]> for Xyz {
+    type Error = ConversionError;
+
+    fn try_from(value: &[String]) -> Result<Self, Self::Error> {
+        let [x, y, z] = value else {
+            return Err(ConversionError::WrongCount {
+                expected: vec![3],
+                found: value.len(),
+            });
+        };
+
+        let y = parse_component(y, "Y")?;
+
+        Ok(Self {
+            x: parse_component(x, "X")?,
+            y: check_range(y, "Y", 0.0..=f64::INFINITY)?,
+            z: parse_component(z, "Z")?,
+        })
+    }
+}
+
+impl ColorSpace for Xyz {
+    const NAME: &'static str = "xyz";
+    const COMPONENTS: &'static [Component] = &[
+        Component::unbounded("x"),
+        Component::unbounded("y"),
+        Component::unbounded("z"),
+    ];
+
+    type Parent = Rgb;
+
+    fn to_parent(&self) -> Rgb {
+        self.to_rgb()
+    }
+
+    fn from_parent(rgb: Rgb) -> Self {
+        Self::from(rgb)
+    }
+
+    fn components(&self) -> Vec<f64> {
+        vec![self.x, self.y, self.z]
+    }
+
+    fn from_components(c: &[f64]) -> Self {
+        Self::from((component(c, 0), component(c, 1), component(c, 2)))
+    }
+
+    fn parse(values: &[String]) -> Result<Self, ConversionError> {
+        Self::try_from(values)
+    }
+}
+
+impl core::fmt::Display for Xyz {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let precision = f.precision().unwrap_or(4);
+
+        f.write_fmt(format_args!(
+            "xyz({}, {}, {})",
+            decimal(self.x, precision),
+            decimal(self.y, precision),
+            decimal(self.z, precision)
+        ))
+    }
+}
+
+getters!(Xyz { x, y, z });
+
+#[cfg(test)]
+mod tests {
+    use crate::format::Rgb;
+
+    use super::Xyz;
+
+    #[test]
+    fn white_from_rgb() {
+        let (x, y, z) = Xyz::from(Rgb::from((255, 255, 255))).into_tuple();
+
+        assert!((x - 0.95046).abs() < 1e-4);
+        assert!((y - 1.0).abs() < 1e-4);
+        assert!((z - 1.08906).abs() < 1e-4);
+    }
+
+    #[test]
+    fn roundtrip_rgb() {
+        let rgb = Rgb::from((51, 102, 204));
+
+        assert_eq!(Xyz::from(rgb).to_rgb().to_bytes(), rgb.to_bytes());
+    }
+
+    #[test]
+    fn outside_of_srgb_gamut() {
+        // Saturated green is far outside of sRGB gamut.
+        let (r, g, b) = Xyz::from((0.2, 0.7, 0.1)).to_rgb().into_tuple();
+
+        assert!(r < 0.0);
+        assert!(g > 1.0);
+        assert!(b < 0.0);
+    }
+}
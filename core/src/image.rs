@@ -0,0 +1,113 @@
+//! Conversions between [`Color`] and pixels of the
+//! [image](https://docs.rs/image) crate.
+//!
+//! Colors are clamped to sRGB gamut when converted into pixels, and pixels
+//! without alpha channel are converted into opaque colors, e.g.:
+//!
+//! ```
+//! use boja_core::{parse_color, Color};
+//! use image::{Rgb, Rgba};
+//!
+//! let pixel: Rgb<u8> = parse_color("hsl(220, 60%, 50%)").unwrap().into();
+//! assert_eq!(pixel, Rgb([51, 102, 204]));
+//!
+//! let color = Color::from(Rgba([51u8, 102, 204, 128]));
+//! assert_eq!(color.to_string(), "3366cc80");
+//! ```
+
+use image::{Rgb, Rgba};
+
+use crate::{
+    format::{to_byte, to_word, Rgb48},
+    Color,
+};
+
+impl From<Color> for Rgb<u8> {
+    fn from(color: Color) -> Self {
+        Rgb([color.red, color.green, color.blue].map(to_byte))
+    }
+}
+
+impl From<Rgb<u8>> for Color {
+    fn from(Rgb([red, green, blue]): Rgb<u8>) -> Self {
+        Color::from_rgba(red, green, blue, u8::MAX)
+    }
+}
+
+impl From<Color> for Rgba<u8> {
+    fn from(color: Color) -> Self {
+        Rgba([color.red, color.green, color.blue, color.alpha].map(to_byte))
+    }
+}
+
+impl From<Rgba<u8>> for Color {
+    fn from(Rgba([red, green, blue, alpha]): Rgba<u8>) -> Self {
+        Color::from_rgba(red, green, blue, alpha)
+    }
+}
+
+impl From<Color> for Rgb<u16> {
+    fn from(color: Color) -> Self {
+        Rgb([color.red, color.green, color.blue].map(to_word))
+    }
+}
+
+impl From<Rgb<u16>> for Color {
+    fn from(pixel: Rgb<u16>) -> Self {
+        Color::from(Rgb48::from(pixel))
+    }
+}
+
+impl From<Color> for Rgba<u16> {
+    fn from(color: Color) -> Self {
+        Rgba([color.red, color.green, color.blue, color.alpha].map(to_word))
+    }
+}
+
+impl From<Rgba<u16>> for Color {
+    fn from(Rgba([red, green, blue, alpha]): Rgba<u16>) -> Self {
+        Color::from(Rgb48::from((red, green, blue))).with_alpha(alpha as f64 / u16::MAX as f64)
+    }
+}
+
+impl From<Rgb48> for Rgb<u16> {
+    fn from(rgb48: Rgb48) -> Self {
+        let (red, green, blue) = rgb48.into_tuple();
+
+        Rgb([red, green, blue])
+    }
+}
+
+impl From<Rgb<u16>> for Rgb48 {
+    fn from(Rgb([red, green, blue]): Rgb<u16>) -> Self {
+        Rgb48::from((red, green, blue))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use image::{Rgb, Rgba};
+
+    use crate::{format::Rgb48, Color};
+
+    #[test]
+    fn pixels() {
+        let color = Color::from_hex_u32_rgba(0x3366cc80);
+
+        assert_eq!(Rgb::<u8>::from(color), Rgb([51, 102, 204]));
+        assert_eq!(Rgba::<u8>::from(color), Rgba([51, 102, 204, 128]));
+        assert_eq!(Color::from(Rgba([51u8, 102, 204, 128])), color);
+        assert_eq!(Color::from(Rgb([51u8, 102, 204])), color.with_alpha(1.0));
+
+        assert_eq!(Rgba::<u16>::from(color), Rgba([13107, 26214, 52428, 32896]));
+        assert_eq!(Color::from(Rgba([13107u16, 26214, 52428, 32896])), color);
+        assert_eq!(Rgb::<u16>::from(Rgb48::from((1, 2, 3))), Rgb([1, 2, 3]));
+    }
+
+    #[test]
+    fn out_of_gamut() {
+        let color = Color::new(1.2, 0.5, -0.1, 1.0);
+
+        assert_eq!(Rgb::<u8>::from(color), Rgb([255, 128, 0]));
+    }
+}
@@ -0,0 +1,425 @@
+//! Colors, formats and parser of [boja](https://docs.rs/boja), which
+//! re-exports all of this crate. It is a separate crate so that the `color!`
+//! macro of boja-macros can parse colors at compile time while boja
+//! re-exports the macro.
+//!
+//! # Features
+//!
+//! - `std`: parsing of colors and configuration files. Without it, the crate
+//!   is `no_std` and only needs an allocator.
+//! - `serde`: `Serialize` and `Deserialize` for [`Color`] and all formats.
+//! - `image`: conversions between [`Color`] and pixels of the image crate.
+//! - `palette`: conversions between [`Color`], formats and color types of the
+//!   palette crate.
+//! - `clap`: `ValueEnum` for options, used as arguments of the command line
+//!   tool.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+mod config;
+pub mod error;
+pub mod format;
+#[cfg(feature = "image")]
+pub mod image;
+#[cfg(not(feature = "std"))]
+mod math;
+mod options;
+#[cfg(feature = "palette")]
+pub mod palette;
+#[cfg(feature = "std")]
+mod parser;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod typed;
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+use error::ConversionError;
+use format::{
+    ColorSpace, Hex, Hlg, Ictcp, Jzazbz, Jzczhz, Latex, Literal, Pq, Rgb, SpaceRgb, Xyy, Xyz,
+    D65_CHROMATICITY,
+};
+
+#[cfg(feature = "std")]
+pub use config::Config;
+pub use options::*;
+#[cfg(feature = "std")]
+pub use parser::{parse_color, parse_color_with};
+
+/// Internal color representation.
+///
+/// Channels are stored as gamma encoded sRGB floating point numbers in range
+/// 0..1, so that no precision is lost between conversions. Values are rounded
+/// only when converted to output format.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Color {
+    red: f64,
+    green: f64,
+    blue: f64,
+    alpha: f64,
+}
+
+impl Color {
+    /// Creates color from gamma encoded sRGB channels and alpha. Channels
+    /// outside of range 0..1 represent colors outside of the sRGB gamut.
+    pub const fn new(red: f64, green: f64, blue: f64, alpha: f64) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    /// Creates color from 8 bit channels and alpha.
+    pub const fn from_rgba(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+        Self {
+            red: red as f64 / 255.0,
+            green: green as f64 / 255.0,
+            blue: blue as f64 / 255.0,
+            alpha: alpha as f64 / 255.0,
+        }
+    }
+
+    /// Creates opaque color from hexadecimal RGB value, e.g. `0x3366cc`. Bits
+    /// above the lowest 24 are ignored.
+    pub const fn from_hex_u32(rgb: u32) -> Self {
+        let [_, red, green, blue] = rgb.to_be_bytes();
+
+        Self::from_rgba(red, green, blue, 0xff)
+    }
+
+    /// Creates color from hexadecimal RGBA value, e.g. `0x3366cc80`.
+    pub const fn from_hex_u32_rgba(rgba: u32) -> Self {
+        let [red, green, blue, alpha] = rgba.to_be_bytes();
+
+        Self::from_rgba(red, green, blue, alpha)
+    }
+
+    /// Creates color from channels given as floating point numbers in range
+    /// 0..1, as used by many programming languages and graphics APIs.
+    pub fn from_unit_rgba(red: f64, green: f64, blue: f64, alpha: f64) -> Self {
+        let channel = |c: f64| c.clamp(0.0, 1.0);
+
+        Self {
+            red: channel(red),
+            green: channel(green),
+            blue: channel(blue),
+            alpha: channel(alpha),
+        }
+    }
+
+    /// Gamma encoded sRGB red channel.
+    pub const fn red(&self) -> f64 {
+        self.red
+    }
+
+    /// Gamma encoded sRGB green channel.
+    pub const fn green(&self) -> f64 {
+        self.green
+    }
+
+    /// Gamma encoded sRGB blue channel.
+    pub const fn blue(&self) -> f64 {
+        self.blue
+    }
+
+    /// Alpha channel in range 0..1, where 1 is fully opaque.
+    pub const fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Returns the same color with given alpha.
+    pub const fn with_alpha(self, alpha: f64) -> Self {
+        Self { alpha, ..self }
+    }
+
+    /// Returns `true` if all channels are within range 0..1, i.e. the color
+    /// fits into sRGB gamut and can be represented by bounded formats without
+    /// clamping.
+    pub fn is_in_range(&self) -> bool {
+        // Tolerate rounding errors accumulated during conversions and errors
+        // of decimal input, which are well below 8 bit precision.
+        const EPSILON: f64 = 1e-4;
+
+        [self.red, self.green, self.blue]
+            .iter()
+            .all(|c| (-EPSILON..=1.0 + EPSILON).contains(c))
+    }
+
+    /// Returns color with channels clamped to range 0..1.
+    pub fn clamped(&self) -> Self {
+        Self {
+            alpha: self.alpha,
+            ..Color::from(Rgb::from(*self).clamped())
+        }
+    }
+
+    /// Maps color into sRGB gamut using given method. Returns the mapped color
+    /// and whether it had to be changed, i.e. whether it was outside of gamut.
+    pub fn map_to_gamut(&self, mapping: GamutMapping) -> (Self, bool) {
+        if self.is_in_range() {
+            return (self.clamped(), false);
+        }
+
+        let mapped = Self {
+            alpha: self.alpha,
+            ..Color::from(mapping.map(Rgb::from(*self)))
+        };
+
+        (mapped, true)
+    }
+
+    /// Mixes color with `other` like CSS `color-mix()`, where `ratio` in range
+    /// 0..1 is the amount of `other` in the result. Hues are interpolated
+    /// along the shorter arc, see [`Color::mix_with`].
+    pub fn mix(&self, other: Color, ratio: f64, space: InterpolationSpace) -> Self {
+        self.mix_with(other, ratio, space, HueInterpolation::Shorter)
+    }
+
+    /// Mixes color with `other` in given space, interpolating hues of
+    /// cylindrical spaces using given method. Components are premultiplied by
+    /// alpha, and alpha itself is interpolated linearly.
+    pub fn mix_with(
+        &self,
+        other: Color,
+        ratio: f64,
+        space: InterpolationSpace,
+        hue: HueInterpolation,
+    ) -> Self {
+        format::mix(*self, other, ratio, space, hue)
+    }
+
+    /// Converts color into given format using default options.
+    pub fn convert(&self, fmt: Format) -> Result<String, ConversionError> {
+        self.convert_with(fmt, &Options::default())
+    }
+
+    /// Converts color into given format using options. Fails if an option
+    /// is not valid for the format, e.g. [`Options::name`] with characters
+    /// special to TeX in [`Format::Latex`].
+    pub fn convert_with(&self, fmt: Format, options: &Options) -> Result<String, ConversionError> {
+        if fmt == Format::Latex {
+            format::check_color_name(&options.name)?;
+        }
+
+        let color = if fmt.is_bounded() {
+            self.map_to_gamut(options.gamut_mapping).0
+        } else {
+            *self
+        };
+
+        // Formats without options are written from their registered space.
+        if let Some(registration) = format::of_format(fmt) {
+            return Ok(registration.write(color, options.precision));
+        }
+
+        let output = match fmt {
+            Format::Hex => Hex::new(options.hex).format(color),
+            Format::Hex48 => Hex::deep(options.hex).format(color),
+            Format::Xyz => with_precision(color.to_xyz(options), options.precision),
+            Format::Xyy => with_precision(
+                Xyy::relative_to(color.to_xyz(options), options.white_point.chromaticity()),
+                options.precision,
+            ),
+            Format::Pq => with_precision(
+                Pq::new(Rgb::from(color), options.peak_nits),
+                options.precision,
+            ),
+            Format::Hlg => with_precision(
+                Hlg::new(Rgb::from(color), options.peak_nits),
+                options.precision,
+            ),
+            Format::Ictcp => with_precision(
+                Ictcp::new(Rgb::from(color), options.peak_nits),
+                options.precision,
+            ),
+            Format::Jzazbz => with_precision(
+                Jzazbz::new(Rgb::from(color), options.peak_nits),
+                options.precision,
+            ),
+            Format::Jzczhz => with_precision(
+                Jzczhz::from(Jzazbz::new(Rgb::from(color), options.peak_nits)),
+                options.precision,
+            ),
+            Format::Color => with_precision(
+                SpaceRgb::new(&options.space, Rgb::from(color), options.adaptation),
+                options.precision,
+            ),
+            Format::Rust => color.convert_to_literal(Literal::RustTuple, options),
+            Format::RustStruct => color.convert_to_literal(Literal::RustStruct, options),
+            Format::C => color.convert_to_literal(Literal::C, options),
+            Format::Swiftui => color.convert_to_literal(Literal::SwiftUi, options),
+            Format::Uikit => color.convert_to_literal(Literal::UiKit, options),
+            Format::Android => color.convert_to_literal(Literal::AndroidXml, options),
+            Format::Flutter => color.convert_to_literal(Literal::Flutter, options),
+            Format::Unity => color.convert_to_literal(Literal::Unity, options),
+            Format::Glsl => color.convert_to_literal(Literal::GlslVec3, options),
+            Format::GlslVec4 => color.convert_to_literal(Literal::GlslVec4, options),
+            Format::Compose => color.convert_to_literal(Literal::Compose, options),
+            Format::Latex => {
+                Latex::new(&options.name, options.latex_model).format(color, options.precision)
+            }
+            _ => {
+                return Err(ConversionError::NoConversion {
+                    from: String::from(Rgb::NAME),
+                    to: format!("{fmt:?}").to_lowercase(),
+                })
+            }
+        };
+
+        Ok(output)
+    }
+
+    /// Converts to XYZ relative to the white point given in options.
+    fn to_xyz(self, options: &Options) -> Xyz {
+        options.adaptation.adapt(
+            Xyz::from(Rgb::from(self)),
+            D65_CHROMATICITY,
+            options.white_point.chromaticity(),
+        )
+    }
+
+    fn convert_to_literal(self, literal: Literal, options: &Options) -> String {
+        literal.format(self, options)
+    }
+}
+
+impl core::fmt::Display for Color {
+    /// Writes color as hexadecimal RGB, with alpha if the color is not opaque.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.convert(Format::Hex).map_err(|_| core::fmt::Error)?)
+    }
+}
+
+/// Formats value with given number of decimal places, or with default
+/// precision of the format if none is given.
+fn with_precision(value: impl core::fmt::Display, precision: Option<usize>) -> String {
+    match precision {
+        Some(precision) => format!("{value:.precision$}"),
+        None => value.to_string(),
+    }
+}
+
+impl TryFrom<&[char]> for Color {
+    type Error = ConversionError;
+
+    /// Parses hexadecimal digits with 4, 8, 12 or 16 bits per channel and
+    /// optional alpha, e.g. `36c`, `3366cc80` or `33336666cccc`.
+    fn try_from(value: &[char]) -> Result<Self, Self::Error> {
+        let (channels, width) = match value.len() {
+            3 => (3, 1),
+            4 => (4, 1),
+            6 => (3, 2),
+            8 => (4, 2),
+            9 => (3, 3),
+            12 => (3, 4),
+            16 => (4, 4),
+            found => {
+                return Err(ConversionError::WrongCount {
+                    expected: vec![3, 4, 6, 8, 9, 12, 16],
+                    found,
+                })
+            }
+        };
+
+        // Largest value of channel with given number of digits, e.g. 0xff.
+        let max = (16u32.pow(width as u32) - 1) as f64;
+
+        let mut rgba = [1.0; 4];
+        let names = ["red", "green", "blue", "alpha"];
+
+        for ((channel, digits), name) in rgba
+            .iter_mut()
+            .zip(value.chunks(width))
+            .zip(names)
+            .take(channels)
+        {
+            let digits = digits.iter().collect::<String>();
+            let parsed = u32::from_str_radix(&digits, 16).map_err(|_| ConversionError::Parse {
+                component: name,
+                value: digits,
+            })?;
+
+            *channel = parsed as f64 / max;
+        }
+
+        let [red, green, blue, alpha] = rgba;
+
+        Ok(Self {
+            red,
+            green,
+            blue,
+            alpha,
+        })
+    }
+}
+
+impl TryFrom<&[Vec<char>]> for Color {
+    type Error = ConversionError;
+
+    /// Parses red, green and blue channels in range 0..255, given as
+    /// sequences of characters.
+    fn try_from(value: &[Vec<char>]) -> Result<Self, Self::Error> {
+        let value = value
+            .iter()
+            .map(|chars| chars.iter().collect::<String>())
+            .collect::<Vec<_>>();
+
+        Color::try_from(value.as_slice())
+    }
+}
+
+impl TryFrom<&[&str]> for Color {
+    type Error = ConversionError;
+
+    /// Parses red, green and blue channels in range 0..255.
+    fn try_from(value: &[&str]) -> Result<Self, Self::Error> {
+        format::check_count(value, &[3])?;
+
+        let mut channels = [0f64; 3];
+
+        for ((channel, value), name) in channels.iter_mut().zip(value).zip(["red", "green", "blue"])
+        {
+            let parsed = format::parse_component(value, name)?;
+            *channel = format::check_range(parsed, name, 0.0..=255.0)? / 255.0;
+        }
+
+        Ok(Color::from(Rgb::from((
+            channels[0],
+            channels[1],
+            channels[2],
+        ))))
+    }
+}
+
+impl TryFrom<&[String]> for Color {
+    type Error = ConversionError;
+
+    fn try_from(value: &[String]) -> Result<Self, Self::Error> {
+        let value = value.iter().map(String::as_str).collect::<Vec<_>>();
+
+        Color::try_from(&value[..])
+    }
+}
+
+impl From<Rgb> for Color {
+    fn from(rgb: Rgb) -> Self {
+        let (red, green, blue) = rgb.into_tuple();
+
+        Self {
+            red,
+            green,
+            blue,
+            alpha: 1.0,
+        }
+    }
+}
@@ -0,0 +1,120 @@
+//! Floating point functions of `std`, provided by [`libm`] without it.
+//!
+//! Modules using any of these functions import [`Float`] when built without
+//! `std`, so that the same code calls inherent methods of [`f64`] with `std`.
+//! Tests always link `std`, whose inherent methods take precedence over the
+//! trait, so modules don't import it in tests and the functions are compared
+//! with `std` here instead.
+
+/// Methods of [`f64`] missing in `core`.
+pub(crate) trait Float {
+    fn atan2(self, other: f64) -> f64;
+    fn cbrt(self) -> f64;
+    fn exp(self) -> f64;
+    fn fract(self) -> f64;
+    fn hypot(self, other: f64) -> f64;
+    fn ln(self) -> f64;
+    fn log10(self) -> f64;
+    fn powf(self, n: f64) -> f64;
+    fn powi(self, n: i32) -> f64;
+    fn rem_euclid(self, rhs: f64) -> f64;
+    fn round(self) -> f64;
+    fn sin_cos(self) -> (f64, f64);
+    fn sqrt(self) -> f64;
+}
+
+impl Float for f64 {
+    fn atan2(self, other: f64) -> f64 {
+        libm::atan2(self, other)
+    }
+
+    fn cbrt(self) -> f64 {
+        libm::cbrt(self)
+    }
+
+    fn exp(self) -> f64 {
+        libm::exp(self)
+    }
+
+    fn fract(self) -> f64 {
+        self - libm::trunc(self)
+    }
+
+    fn hypot(self, other: f64) -> f64 {
+        libm::hypot(self, other)
+    }
+
+    fn ln(self) -> f64 {
+        libm::log(self)
+    }
+
+    fn log10(self) -> f64 {
+        libm::log10(self)
+    }
+
+    fn powf(self, n: f64) -> f64 {
+        libm::pow(self, n)
+    }
+
+    fn powi(self, n: i32) -> f64 {
+        libm::pow(self, n as f64)
+    }
+
+    fn rem_euclid(self, rhs: f64) -> f64 {
+        let r = libm::fmod(self, rhs);
+
+        if r < 0.0 {
+            r + rhs.abs()
+        } else {
+            r
+        }
+    }
+
+    fn round(self) -> f64 {
+        libm::round(self)
+    }
+
+    fn sin_cos(self) -> (f64, f64) {
+        libm::sincos(self)
+    }
+
+    fn sqrt(self) -> f64 {
+        libm::sqrt(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Float;
+
+    #[test]
+    fn matches_std() {
+        for x in [-2.5, -1.0, -0.3, 0.0, 0.2, 0.5, 1.0, 3.7, 100.0] {
+            let close = |a: f64, b: f64| {
+                a == b || (a - b).abs() <= 1e-12 * b.abs().max(1.0) || a.is_nan() && b.is_nan()
+            };
+
+            assert!(close(<f64 as Float>::atan2(x, 0.7), x.atan2(0.7)), "{x}");
+            assert!(close(<f64 as Float>::cbrt(x), x.cbrt()), "{x}");
+            assert!(close(<f64 as Float>::exp(x), x.exp()), "{x}");
+            assert!(close(<f64 as Float>::fract(x), x.fract()), "{x}");
+            assert!(close(<f64 as Float>::hypot(x, 1.5), x.hypot(1.5)), "{x}");
+            assert!(close(<f64 as Float>::ln(x), x.ln()), "{x}");
+            assert!(close(<f64 as Float>::log10(x), x.log10()), "{x}");
+            assert!(
+                close(<f64 as Float>::powf(x.abs(), 2.4), x.abs().powf(2.4)),
+                "{x}"
+            );
+            assert!(close(<f64 as Float>::powi(x, 3), x.powi(3)), "{x}");
+            assert!(
+                close(<f64 as Float>::rem_euclid(x, 360.0), x.rem_euclid(360.0)),
+                "{x}"
+            );
+            assert!(close(<f64 as Float>::round(x), x.round()), "{x}");
+            assert!(close(<f64 as Float>::sqrt(x), x.sqrt()), "{x}");
+
+            let (sin, cos) = <f64 as Float>::sin_cos(x);
+            assert!(close(sin, x.sin()) && close(cos, x.cos()), "{x}");
+        }
+    }
+}
@@ -0,0 +1,576 @@
+//! Options of conversion into output formats.
+
+use core::{fmt::Display, str::FromStr};
+
+use alloc::string::{String, ToString};
+
+use crate::{
+    error::ConversionError,
+    format::{self, check_range, parse_component, RgbSpace, DEFAULT_PEAK_NITS},
+};
+
+/// Options controlling how colors are converted to output formats.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Options {
+    /// Color model used by [`Format::Latex`].
+    pub latex_model: LatexModel,
+
+    /// Name of the color in formats that define named colors. LaTeX names
+    /// must consist of ASCII letters and digits.
+    pub name: String,
+
+    /// Style of [`Format::Hex`] output.
+    pub hex: HexOptions,
+
+    /// Number of decimal places in formats with decimal values. Each format
+    /// uses its own default precision if none is given.
+    pub precision: Option<usize>,
+
+    /// Peak luminance in nits used by HDR formats, e.g. [`Format::Pq`].
+    /// Linear color channel value of 1 is mapped to this luminance.
+    pub peak_nits: f64,
+
+    /// RGB color space used by [`Format::Color`].
+    pub space: RgbSpace,
+
+    /// Reference white of XYZ based formats, e.g. [`Format::Xyz`].
+    pub white_point: WhitePoint,
+
+    /// Transform used to adapt colors between white points.
+    pub adaptation: Adaptation,
+
+    /// Method of fitting colors into formats bounded to sRGB gamut, see
+    /// [`Format::is_bounded`].
+    pub gamut_mapping: GamutMapping,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            latex_model: LatexModel::RgbInt,
+            name: String::from("color"),
+            hex: HexOptions::default(),
+            precision: None,
+            peak_nits: DEFAULT_PEAK_NITS,
+            space: RgbSpace::srgb(),
+            white_point: WhitePoint::D65,
+            adaptation: Adaptation::Bradford,
+            gamut_mapping: GamutMapping::Css,
+        }
+    }
+}
+
+/// Options controlling the style of hexadecimal output.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HexOptions {
+    pub case: HexCase,
+    pub prefix: HexPrefix,
+
+    /// Use 3 or 4 digit shorthand when no information is lost, e.g. `fff`
+    /// instead of `ffffff`.
+    pub short: bool,
+
+    /// Placement of alpha for colors that are not fully opaque. Alpha is
+    /// omitted for opaque colors.
+    pub alpha: AlphaPosition,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum HexCase {
+    #[default]
+    #[cfg_attr(feature = "clap", value(help = "Lowercase digits, e.g. 3366cc"))]
+    Lower,
+
+    #[cfg_attr(feature = "clap", value(help = "Uppercase digits, e.g. 3366CC"))]
+    Upper,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum HexPrefix {
+    #[default]
+    #[cfg_attr(feature = "clap", value(help = "No prefix, e.g. 3366cc"))]
+    None,
+
+    #[cfg_attr(feature = "clap", value(help = "Hash prefix, e.g. #3366cc"))]
+    Hash,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(name = "0x", help = "Hexadecimal literal prefix, e.g. 0x3366cc")
+    )]
+    ZeroX,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum AlphaPosition {
+    #[default]
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "Alpha after color channels, e.g. 3366cc80 (RRGGBBAA)")
+    )]
+    Last,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "Alpha before color channels, e.g. 803366cc (AARRGGBB)")
+    )]
+    First,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum Format {
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "Hexadecimal representation of RGB color. Example for white: ffffff")
+    )]
+    Hex,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "RGB representation of color. Example for white: rgb(255, 255, 255)")
+    )]
+    Rgb,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(
+            help = "Hexadecimal representation with 16 bits per channel. Example for white: ffffffffffff"
+        )
+    )]
+    Hex48,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(
+            help = "RGB representation with 16 bits per channel. Example for white: rgb48(65535, 65535, 65535)"
+        )
+    )]
+    Rgb48,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "HSL representation of color. Example for white hsl(0, 0, 100)")
+    )]
+    Hsl,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "HSV representation of color. Example for white: hsv(0, 0, 100)")
+    )]
+    Hsv,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(
+            help = "CIE XYZ relative to --white-point. Example for white: xyz(0.9505, 1.0000, 1.0891)"
+        )
+    )]
+    Xyz,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(
+            help = "CIE xyY relative to --white-point. Example for white: xyy(0.3127, 0.3290, 1.0)"
+        )
+    )]
+    Xyy,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(
+            help = "Oklab perceptual color space. Example for white: oklab(1.0000, 0.0000, 0.0000)"
+        )
+    )]
+    Oklab,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(
+            help = "Oklch, the cylindrical form of Oklab. Example for white: oklch(1.0000, 0.0000, 0.0000)"
+        )
+    )]
+    Oklch,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(
+            help = "scRGB linear light representation with extended range. Example for white: scrgb(1.0, 1.0, 1.0)"
+        )
+    )]
+    Scrgb,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(
+            help = "Rec. 2100 PQ encoded color, see --peak-nits. Example for white: pq(0.7518, 0.7518, 0.7518)"
+        )
+    )]
+    Pq,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(
+            help = "Rec. 2100 HLG encoded color, see --peak-nits. Example for white: hlg(1.0, 1.0, 1.0)"
+        )
+    )]
+    Hlg,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(
+            help = "ICtCp for PQ encoding, see --peak-nits. Example for white: ictcp(0.7518, 0.0000, 0.0000)"
+        )
+    )]
+    Ictcp,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(
+            help = "Jzazbz perceptual color space for HDR, see --peak-nits. Example for white: jzazbz(0.40912, -0.00020, -0.00015)"
+        )
+    )]
+    Jzazbz,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(
+            help = "JzCzhz, the cylindrical form of Jzazbz, see --peak-nits. Example for white: jzczhz(0.40912, 0.00025, 216.07649)"
+        )
+    )]
+    Jzczhz,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(
+            help = "CSS color() function in RGB space given by --space. Example for white: color(srgb 1.0000 1.0000 1.0000)"
+        )
+    )]
+    Color,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "Rust tuple literal. Example for white: (255, 255, 255)")
+    )]
+    Rust,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "Rust struct literal. Example for white: Rgb { r: 255, g: 255, b: 255 }")
+    )]
+    RustStruct,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "C initializer. Example for white: {255, 255, 255}")
+    )]
+    C,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "SwiftUI color. Example for white: Color(red: 1.0, green: 1.0, blue: 1.0)")
+    )]
+    Swiftui,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(
+            help = "UIKit color. Example for white: UIColor(red: 1.0, green: 1.0, blue: 1.0, alpha: 1.0)"
+        )
+    )]
+    Uikit,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(
+            help = "Android XML color resource. Example for white: <color name=\"color\">#FFFFFFFF</color>"
+        )
+    )]
+    Android,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "Flutter color. Example for white: Color(0xFFFFFFFF)")
+    )]
+    Flutter,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "Unity color. Example for white: new Color(1.0f, 1.0f, 1.0f)")
+    )]
+    Unity,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "GLSL vec3. Example for white: vec3(1.0, 1.0, 1.0)")
+    )]
+    Glsl,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "GLSL vec4 with alpha. Example for white: vec4(1.0, 1.0, 1.0, 1.0)")
+    )]
+    GlslVec4,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "Kotlin Jetpack Compose color. Example for white: Color(0xFFFFFFFF)")
+    )]
+    Compose,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(
+            help = "LaTeX xcolor definition, see --latex-model. Example for white: \\definecolor{color}{RGB}{255,255,255}"
+        )
+    )]
+    Latex,
+}
+
+impl Format {
+    /// Returns `true` if the format can only represent colors within sRGB
+    /// gamut, so colors outside of it are clamped when converted.
+    pub fn is_bounded(self) -> bool {
+        !matches!(
+            self,
+            Format::Xyz
+                | Format::Xyy
+                | Format::Oklab
+                | Format::Oklch
+                | Format::Scrgb
+                | Format::Pq
+                | Format::Hlg
+                | Format::Ictcp
+                | Format::Jzazbz
+                | Format::Jzczhz
+                | Format::Color
+                | Format::Swiftui
+                | Format::Uikit
+                | Format::Unity
+                | Format::Glsl
+                | Format::GlslVec4
+        )
+    }
+
+    /// Returns name of the color space of the format, see
+    /// [`ColorSpace::NAME`](crate::format::ColorSpace::NAME), if the format is written only from the space
+    /// without any options.
+    pub fn space(self) -> Option<&'static str> {
+        format::of_format(self).map(|registration| registration.name)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum LatexModel {
+    #[cfg_attr(
+        feature = "clap",
+        value(name = "RGB", help = "Integer RGB values in range 0-255")
+    )]
+    RgbInt,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(name = "rgb", help = "Floating point RGB values in range 0-1")
+    )]
+    Rgb,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(name = "HTML", alias = "html", help = "Hexadecimal RGB value")
+    )]
+    Html,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "Floating point CMYK values in range 0-1")
+    )]
+    Cmyk,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "Floating point gray level in range 0-1")
+    )]
+    Gray,
+}
+
+/// Reference white given by its chromaticity coordinates, either one of the
+/// CIE standard illuminants or a custom one.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum WhitePoint {
+    A,
+    C,
+    D50,
+    D55,
+    #[default]
+    D65,
+    D75,
+    E,
+    F2,
+    F7,
+    F11,
+    Custom(f64, f64),
+}
+
+impl FromStr for WhitePoint {
+    type Err = ConversionError;
+
+    /// Parses name of standard illuminant, e.g. `D50`, or chromaticity
+    /// coordinates separated by comma, e.g. `0.3457,0.3585`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let white = match s.to_uppercase().as_str() {
+            "A" => WhitePoint::A,
+            "C" => WhitePoint::C,
+            "D50" => WhitePoint::D50,
+            "D55" => WhitePoint::D55,
+            "D65" => WhitePoint::D65,
+            "D75" => WhitePoint::D75,
+            "E" => WhitePoint::E,
+            "F2" => WhitePoint::F2,
+            "F7" => WhitePoint::F7,
+            "F11" => WhitePoint::F11,
+            _ => {
+                let (x, y) = s.split_once(',').ok_or_else(|| ConversionError::Parse {
+                    component: "white point",
+                    value: s.to_string(),
+                })?;
+                let x = parse_component(x.trim(), "white point x")?;
+                let y = parse_component(y.trim(), "white point y")?;
+
+                WhitePoint::Custom(
+                    check_range(x, "white point x", 0.0..=1.0)?,
+                    check_range(y, "white point y", f64::MIN_POSITIVE..=1.0)?,
+                )
+            }
+        };
+
+        Ok(white)
+    }
+}
+
+impl Display for WhitePoint {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            WhitePoint::Custom(x, y) => write!(f, "{x},{y}"),
+            named => write!(f, "{named:?}"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum Adaptation {
+    #[default]
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "Bradford transform, as used by ICC profiles and CSS")
+    )]
+    Bradford,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "Von Kries transform with Hunt-Pointer-Estevez cone responses")
+    )]
+    VonKries,
+
+    #[cfg_attr(feature = "clap", value(name = "cat02", help = "CIECAM02 transform"))]
+    Cat02,
+
+    #[cfg_attr(feature = "clap", value(name = "cat16", help = "CAM16 transform"))]
+    Cat16,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "Scaling of XYZ values, physically least accurate")
+    )]
+    XyzScaling,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum GamutMapping {
+    #[default]
+    #[cfg_attr(
+        feature = "clap",
+        value(
+            help = "CSS Color 4 algorithm, reducing Oklch chroma until clipping is unnoticeable"
+        )
+    )]
+    Css,
+
+    #[cfg_attr(feature = "clap", value(help = "Clamp each channel to the gamut"))]
+    Clip,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "Desaturate in linear light towards gray of the same luminance")
+    )]
+    Scale,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "Reduce Oklch chroma until the color fits, keeping lightness and hue")
+    )]
+    PreserveLightness,
+}
+
+/// Color space in which colors are mixed, as in CSS `color-mix()`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum InterpolationSpace {
+    #[cfg_attr(feature = "clap", value(help = "Gamma encoded sRGB channels"))]
+    Srgb,
+
+    #[cfg_attr(feature = "clap", value(help = "Linear light sRGB channels"))]
+    SrgbLinear,
+
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "CIE Lab relative to D50, as used by CSS")
+    )]
+    Lab,
+
+    #[cfg_attr(feature = "clap", value(help = "CIE LCh, the cylindrical form of Lab"))]
+    Lch,
+
+    #[default]
+    #[cfg_attr(
+        feature = "clap",
+        value(help = "Oklab perceptual color space, the default of CSS")
+    )]
+    Oklab,
+
+    #[cfg_attr(feature = "clap", value(help = "Oklch, the cylindrical form of Oklab"))]
+    Oklch,
+
+    #[cfg_attr(feature = "clap", value(help = "Hue, saturation and lightness"))]
+    Hsl,
+
+    #[cfg_attr(feature = "clap", value(help = "Hue, whiteness and blackness"))]
+    Hwb,
+
+    #[cfg_attr(feature = "clap", value(help = "CIE XYZ relative to D65"))]
+    Xyz,
+}
+
+/// Direction in which hues are interpolated in cylindrical spaces, e.g.
+/// [`InterpolationSpace::Oklch`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+pub enum HueInterpolation {
+    #[default]
+    #[cfg_attr(feature = "clap", value(help = "Shorter arc between the hues"))]
+    Shorter,
+
+    #[cfg_attr(feature = "clap", value(help = "Longer arc between the hues"))]
+    Longer,
+
+    #[cfg_attr(feature = "clap", value(help = "Arc with increasing hue angle"))]
+    Increasing,
+
+    #[cfg_attr(feature = "clap", value(help = "Arc with decreasing hue angle"))]
+    Decreasing,
+}
@@ -0,0 +1,225 @@
+//! Conversions between [`Color`], formats and color types of the
+//! [palette](https://docs.rs/palette) crate.
+//!
+//! Colors are converted into [`Srgb`] and [`Srgba`] with `f64` or `u8`
+//! components, and formats into their counterparts in palette, e.g. [`Hsl`]
+//! into [`palette::Hsl`]. Conversions between floating point types keep colors
+//! outside of sRGB gamut, so palette can be used for further processing:
+//!
+//! ```
+//! use boja_core::{format::Oklch, Color};
+//! use palette::{Darken, Srgb};
+//!
+//! let color = Color::from_hex_u32(0x3366cc);
+//! let darker = palette::Oklch::from(Oklch::from(color)).darken(0.2);
+//!
+//! assert_eq!(Color::from(Oklch::from(darker)).to_string(), "1545a8");
+//! assert_eq!(Srgb::<u8>::from(color), Srgb::new(51, 102, 204));
+//! ```
+
+use palette::{encoding, white_point::D65, OklabHue, RgbHue, Srgb, Srgba};
+
+use crate::{
+    format::{to_byte, Hsl, Hsv, Oklab, Oklch, Rgb, Xyz},
+    Color,
+};
+
+impl From<Color> for Srgba<f64> {
+    fn from(color: Color) -> Self {
+        Srgba::new(color.red, color.green, color.blue, color.alpha)
+    }
+}
+
+impl From<Srgba<f64>> for Color {
+    fn from(color: Srgba<f64>) -> Self {
+        Color::new(color.red, color.green, color.blue, color.alpha)
+    }
+}
+
+impl From<Color> for Srgb<f64> {
+    fn from(color: Color) -> Self {
+        Srgb::new(color.red, color.green, color.blue)
+    }
+}
+
+impl From<Srgb<f64>> for Color {
+    fn from(color: Srgb<f64>) -> Self {
+        Color::new(color.red, color.green, color.blue, 1.0)
+    }
+}
+
+impl From<Color> for Srgba<u8> {
+    fn from(color: Color) -> Self {
+        let [red, green, blue, alpha] =
+            [color.red, color.green, color.blue, color.alpha].map(to_byte);
+
+        Srgba::new(red, green, blue, alpha)
+    }
+}
+
+impl From<Srgba<u8>> for Color {
+    fn from(color: Srgba<u8>) -> Self {
+        Color::from_rgba(color.red, color.green, color.blue, color.alpha)
+    }
+}
+
+impl From<Color> for Srgb<u8> {
+    fn from(color: Color) -> Self {
+        let [red, green, blue] = [color.red, color.green, color.blue].map(to_byte);
+
+        Srgb::new(red, green, blue)
+    }
+}
+
+impl From<Srgb<u8>> for Color {
+    fn from(color: Srgb<u8>) -> Self {
+        Color::from_rgba(color.red, color.green, color.blue, u8::MAX)
+    }
+}
+
+impl From<Rgb> for Srgb<f64> {
+    fn from(rgb: Rgb) -> Self {
+        let (red, green, blue) = rgb.into_tuple();
+
+        Srgb::new(red, green, blue)
+    }
+}
+
+impl From<Srgb<f64>> for Rgb {
+    fn from(rgb: Srgb<f64>) -> Self {
+        Rgb::from((rgb.red, rgb.green, rgb.blue))
+    }
+}
+
+impl From<Hsl> for palette::Hsl<encoding::Srgb, f64> {
+    /// Converts saturation and lightness from percent into range 0..1.
+    fn from(hsl: Hsl) -> Self {
+        let (hue, saturation, lightness) = hsl.into_tuple();
+
+        palette::Hsl::new(
+            RgbHue::from_degrees(hue),
+            saturation / 100.0,
+            lightness / 100.0,
+        )
+    }
+}
+
+impl From<palette::Hsl<encoding::Srgb, f64>> for Hsl {
+    fn from(hsl: palette::Hsl<encoding::Srgb, f64>) -> Self {
+        Hsl::from((
+            hsl.hue.into_positive_degrees(),
+            hsl.saturation * 100.0,
+            hsl.lightness * 100.0,
+        ))
+    }
+}
+
+impl From<Hsv> for palette::Hsv<encoding::Srgb, f64> {
+    /// Converts saturation and value from percent into range 0..1.
+    fn from(hsv: Hsv) -> Self {
+        let (hue, saturation, value) = hsv.into_tuple();
+
+        palette::Hsv::new(RgbHue::from_degrees(hue), saturation / 100.0, value / 100.0)
+    }
+}
+
+impl From<palette::Hsv<encoding::Srgb, f64>> for Hsv {
+    fn from(hsv: palette::Hsv<encoding::Srgb, f64>) -> Self {
+        Hsv::from((
+            hsv.hue.into_positive_degrees(),
+            hsv.saturation * 100.0,
+            hsv.value * 100.0,
+        ))
+    }
+}
+
+impl From<Oklab> for palette::Oklab<f64> {
+    fn from(oklab: Oklab) -> Self {
+        palette::Oklab::new(oklab.lightness(), oklab.a(), oklab.b())
+    }
+}
+
+impl From<palette::Oklab<f64>> for Oklab {
+    fn from(oklab: palette::Oklab<f64>) -> Self {
+        Oklab::from((oklab.l, oklab.a, oklab.b))
+    }
+}
+
+impl From<Oklch> for palette::Oklch<f64> {
+    fn from(oklch: Oklch) -> Self {
+        palette::Oklch::new(
+            oklch.lightness(),
+            oklch.chroma(),
+            OklabHue::from_degrees(oklch.hue()),
+        )
+    }
+}
+
+impl From<palette::Oklch<f64>> for Oklch {
+    fn from(oklch: palette::Oklch<f64>) -> Self {
+        Oklch::from((oklch.l, oklch.chroma, oklch.hue.into_positive_degrees()))
+    }
+}
+
+impl From<Xyz> for palette::Xyz<D65, f64> {
+    fn from(xyz: Xyz) -> Self {
+        let (x, y, z) = xyz.into_tuple();
+
+        palette::Xyz::new(x, y, z)
+    }
+}
+
+impl From<palette::Xyz<D65, f64>> for Xyz {
+    fn from(xyz: palette::Xyz<D65, f64>) -> Self {
+        Xyz::from((xyz.x, xyz.y, xyz.z))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use palette::{FromColor, Srgb, Srgba};
+
+    use crate::{
+        format::{Hsl, Oklch, Xyz},
+        Color,
+    };
+
+    #[test]
+    fn rgb() {
+        let color = Color::from_hex_u32_rgba(0x3366cc80);
+
+        assert_eq!(Srgba::<u8>::from(color), Srgba::new(51, 102, 204, 128));
+        assert_eq!(Color::from(Srgba::<u8>::new(51, 102, 204, 128)), color);
+        assert_eq!(Color::from(Srgba::<f64>::from(color)), color);
+
+        let wide = Color::new(1.2, 0.5, -0.1, 1.0);
+        assert_eq!(Color::from(Srgb::<f64>::from(wide)), wide);
+        assert_eq!(Srgb::<u8>::from(wide), Srgb::new(255, 128, 0));
+    }
+
+    #[test]
+    fn formats() {
+        let color = Color::from_hex_u32(0x3366cc);
+
+        // Conversions of palette agree with boja up to rounding errors.
+        let hsl = palette::Hsl::from(Hsl::from(color));
+        let expected = palette::Hsl::from_color(Srgb::<f64>::from(color));
+        assert!((hsl.hue - expected.hue).into_degrees().abs() < 1e-9);
+        assert!((hsl.saturation - expected.saturation).abs() < 1e-9);
+        assert!((hsl.lightness - expected.lightness).abs() < 1e-9);
+
+        let oklch = palette::Oklch::from(Oklch::from(color));
+        let expected = palette::Oklch::from_color(Srgb::<f64>::from(color));
+        assert!((oklch.l - expected.l).abs() < 1e-4);
+        assert!((oklch.chroma - expected.chroma).abs() < 1e-4);
+
+        let xyz = palette::Xyz::from(Xyz::from(color));
+        let expected = palette::Xyz::from_color(Srgb::<f64>::from(color).into_linear());
+        assert!((xyz.y - expected.y).abs() < 1e-4);
+
+        assert_eq!(Color::from(Hsl::from(hsl)).to_string(), "3366cc");
+        assert_eq!(Color::from(Oklch::from(oklch)).to_string(), "3366cc");
+    }
+}
@@ -0,0 +1,1003 @@
+//! Implementation of the parser for various color formats.
+
+mod utils;
+
+use crate::format::{
+    is_name_char, Hlg, Hsv, Ictcp, Jzazbz, Jzczhz, Lab, Lch, Oklab, Oklch, Pq, Rgb, Rgb48, Scrgb,
+    SpaceRgb, Xyy, Xyz, D65_CHROMATICITY,
+};
+use std::str::FromStr;
+
+use crate::{
+    error::{CustomError, ErrorKind, ParseError},
+    format::{ColorSpace, Hsl},
+};
+use crate::{AlphaPosition, Color, Config};
+use chumsky::{
+    primitive::{choice, end, filter, just, none_of},
+    text::{whitespace, TextParser},
+    Parser,
+};
+
+use self::utils::{
+    arguments, byte, css_arguments, decimal, digit, hex_number, n_digits, named, numbers_between,
+    numbers_separated_by, prefix, signed_decimal, unit_float, Arguments,
+};
+
+impl FromStr for Color {
+    type Err = ParseError;
+
+    /// Parses color in any supported format, see [`parse_color`].
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        parse_color(input).map_err(|errors| first_error(errors, input))
+    }
+}
+
+/// Parses color in functional notation of space `S` with its parser from
+/// the grammar of [`parse_color`], e.g. [`hsl`] for `hsl(220, 60%, 50%)`.
+pub(crate) fn parse_function<S: ColorSpace>(
+    input: &str,
+    parser: impl Parser<char, S, Error = CustomError>,
+) -> Result<S, ParseError> {
+    parser
+        .or(parse_unknown_function(&[S::NAME]))
+        .parse(input)
+        .map_err(|errors| first_error(errors, input))
+}
+
+/// Returns the first of parse errors, as reported by [`FromStr`]
+/// implementations.
+fn first_error(errors: Vec<CustomError>, input: &str) -> ParseError {
+    errors
+        .into_iter()
+        .next()
+        .map(ParseError::from)
+        .unwrap_or(ParseError::Syntax {
+            expected: Vec::new(),
+            found: None,
+            span: 0..input.len(),
+        })
+}
+
+pub fn parse_color(input: &str) -> Result<Color, Vec<CustomError>> {
+    parse_color_with(input, &Config::default())
+}
+
+/// Parses color like [`parse_color`], additionally accepting colors in RGB
+/// spaces defined in the configuration, e.g. `color(studio-monitor 1 0.5 0)`.
+pub fn parse_color_with(input: &str, config: &Config) -> Result<Color, Vec<CustomError>> {
+    let literals = choice((
+        parse_argb_literal(),
+        parse_swiftui_literal(),
+        parse_uikit_literal(),
+        parse_glsl_literal(),
+        parse_unity_literal(),
+        parse_android_literal(),
+        parse_rust_literal(),
+        parse_c_literal(),
+    ));
+
+    let (xyz_config, xyy_config) = (config.clone(), config.clone());
+
+    let parser = choice((
+        parse_hex(config.hex_alpha()),
+        rgb().map(Color::from),
+        rgb48().map(Color::from),
+        hsl().map(Color::from),
+        hsv().map(Color::from),
+        xyz().map(move |xyz| from_xyz(xyz, &xyz_config)),
+        xyy().map(move |xyy| from_xyz(Xyz::from(xyy), &xyy_config)),
+        lab().map(Color::from),
+        lch().map(Color::from),
+        oklab().map(Color::from),
+        oklch().map(Color::from),
+        scrgb().map(Color::from),
+        pq().map(Color::from),
+        hlg().map(Color::from),
+        ictcp().map(Color::from),
+        jzazbz().map(Color::from),
+        jzczhz().map(Color::from),
+        parse_latex(),
+        literals,
+        parse_space_color(config.clone()),
+        parse_unknown_function(&FUNCTIONS),
+    ));
+    parser.parse(input)
+}
+
+fn parse_hex(alpha: AlphaPosition) -> impl Parser<char, Color, Error = CustomError> {
+    let hex_digit = digit(16);
+    let n_hex = |n| hex_digit.repeated().exactly(n).then_ignore(end());
+
+    choice((just("#"), just("0x"))).or_not().ignore_then(
+        choice((
+            n_hex(16),
+            n_hex(12),
+            n_hex(9),
+            n_hex(8),
+            n_hex(6),
+            n_hex(4),
+            n_hex(3),
+        ))
+        .map(move |mut digits| {
+            // Move alpha to the end, where it is expected by `Color`.
+            let width = digits.len() / 4;
+
+            if alpha == AlphaPosition::First && matches!(digits.len(), 4 | 8 | 16) {
+                digits.rotate_left(width);
+            }
+
+            Color::try_from(&digits[..])
+        })
+        .unwrapped()
+        .map_err(|err| CustomError {
+            msg: String::from("Unexpected input. Expected"),
+            span: err.span.clone(),
+            expected: vec![String::from("Hex digit")],
+            found: err.found,
+            kind: ErrorKind::Syntax,
+        }),
+    )
+}
+
+pub(crate) fn rgb() -> impl Parser<char, Rgb, Error = CustomError> {
+    prefix("rgb")
+        .ignore_then(numbers_separated_by(decimal(), 3, ','))
+        .then_ignore(just(')'))
+        .then_ignore(end())
+        .try_map(|rgb, span| {
+            Rgb::parse(&rgb[..]).map_err(|err| CustomError {
+                msg: String::from("Invalid RGB value. Expected"),
+                span: rgb.span_of(&err, span),
+                expected: vec![String::from("Value in range 0-255")],
+                found: vec![err.to_string()],
+                kind: ErrorKind::OutOfRange,
+            })
+        })
+}
+
+pub(crate) fn rgb48() -> impl Parser<char, Rgb48, Error = CustomError> {
+    prefix("rgb48")
+        .ignore_then(numbers_separated_by(n_digits(5, 10), 3, ','))
+        .then_ignore(just(')'))
+        .then_ignore(end())
+        .try_map(|rgb48, span| {
+            Rgb48::try_from(&rgb48[..]).map_err(|err| CustomError {
+                msg: String::from("Invalid RGB48 value. Expected"),
+                span: rgb48.span_of(&err, span),
+                expected: vec![String::from("Value in range 0-65535")],
+                found: vec![err.to_string()],
+                kind: ErrorKind::OutOfRange,
+            })
+        })
+}
+
+pub(crate) fn hsl() -> impl Parser<char, Hsl, Error = CustomError> {
+    let digits = decimal();
+    let digits_maybe_percent = choice((digits.then_ignore(just('%')), digits));
+
+    prefix("hsl")
+        .ignore_then(numbers_separated_by(digits_maybe_percent, 3, ','))
+        .then_ignore(just(')'))
+        .then_ignore(end())
+        .try_map(|hsl, span| {
+            Hsl::try_from(&hsl[..]).map_err(|err| CustomError {
+                msg: String::from("Invalid HSL value. Expected"),
+                span: hsl.span_of(&err, span),
+                expected: vec![String::from(
+                    "Values: 0-360 for hue, 0-100 for saturation and lightness",
+                )],
+                found: vec![err.to_string()],
+                kind: ErrorKind::OutOfRange,
+            })
+        })
+}
+
+pub(crate) fn hsv() -> impl Parser<char, Hsv, Error = CustomError> {
+    prefix("hsv")
+        .ignore_then(numbers_separated_by(decimal(), 3, ','))
+        .then_ignore(just(')'))
+        .then_ignore(end())
+        .try_map(|hsv, span| {
+            Hsv::try_from(&hsv[..]).map_err(|err| CustomError {
+                msg: String::from("Invalid HSV value. Expected"),
+                span: hsv.span_of(&err, span),
+                expected: vec![String::from(
+                    "Values: 0-360 for hue, 0-100 for saturation and value",
+                )],
+                found: vec![err.to_string()],
+                kind: ErrorKind::OutOfRange,
+            })
+        })
+}
+
+pub(crate) fn xyz() -> impl Parser<char, Xyz, Error = CustomError> {
+    prefix("xyz")
+        .ignore_then(numbers_separated_by(signed_decimal(), 3, ','))
+        .then_ignore(just(')'))
+        .then_ignore(end())
+        .try_map(|xyz, span| {
+            Xyz::try_from(&xyz[..]).map_err(|err| CustomError {
+                msg: String::from("Invalid XYZ value. Expected"),
+                span: xyz.span_of(&err, span),
+                expected: vec![String::from(
+                    "Tristimulus values relative to white point with luminance 1",
+                )],
+                found: vec![err.to_string()],
+                kind: ErrorKind::OutOfRange,
+            })
+        })
+}
+
+pub(crate) fn xyy() -> impl Parser<char, Xyy, Error = CustomError> {
+    prefix("xyy")
+        .ignore_then(numbers_separated_by(decimal(), 3, ','))
+        .then_ignore(just(')'))
+        .then_ignore(end())
+        .try_map(|xyy, span| {
+            Xyy::try_from(&xyy[..]).map_err(|err| CustomError {
+                msg: String::from("Invalid xyY value. Expected"),
+                span: xyy.span_of(&err, span),
+                expected: vec![String::from(
+                    "Values: 0-1 for x and y chromaticity (y above 0), non-negative Y luminance",
+                )],
+                found: vec![err.to_string()],
+                kind: ErrorKind::OutOfRange,
+            })
+        })
+}
+
+/// Converts XYZ color relative to the configured white point.
+fn from_xyz(xyz: Xyz, config: &Config) -> Color {
+    let white = config.white_point().chromaticity();
+    let xyz = config.adaptation().adapt(xyz, white, D65_CHROMATICITY);
+
+    Color::from(xyz.to_rgb())
+}
+
+pub(crate) fn lab() -> impl Parser<char, Lab, Error = CustomError> {
+    prefix("lab")
+        .ignore_then(css_arguments(signed_decimal(), 3))
+        .then_ignore(end())
+        .try_map(|lab, span| {
+            Lab::try_from(&lab[..]).map_err(|err| CustomError {
+                msg: String::from("Invalid Lab value. Expected"),
+                span: lab.span_of(&err, span),
+                expected: vec![String::from("Values: 0-100 for lightness, a and b")],
+                found: vec![err.to_string()],
+                kind: ErrorKind::OutOfRange,
+            })
+        })
+}
+
+pub(crate) fn lch() -> impl Parser<char, Lch, Error = CustomError> {
+    prefix("lch")
+        .ignore_then(css_arguments(signed_decimal(), 3))
+        .then_ignore(end())
+        .try_map(|lch, span| {
+            Lch::try_from(&lch[..]).map_err(|err| CustomError {
+                msg: String::from("Invalid LCh value. Expected"),
+                span: lch.span_of(&err, span),
+                expected: vec![String::from(
+                    "Values: 0-100 for lightness, non-negative chroma, hue in degrees",
+                )],
+                found: vec![err.to_string()],
+                kind: ErrorKind::OutOfRange,
+            })
+        })
+}
+
+pub(crate) fn oklab() -> impl Parser<char, Oklab, Error = CustomError> {
+    prefix("oklab")
+        .ignore_then(css_arguments(signed_decimal(), 3))
+        .then_ignore(end())
+        .try_map(|oklab, span| {
+            Oklab::try_from(&oklab[..]).map_err(|err| CustomError {
+                msg: String::from("Invalid Oklab value. Expected"),
+                span: oklab.span_of(&err, span),
+                expected: vec![String::from("Values: 0-1 for lightness, a and b")],
+                found: vec![err.to_string()],
+                kind: ErrorKind::OutOfRange,
+            })
+        })
+}
+
+pub(crate) fn oklch() -> impl Parser<char, Oklch, Error = CustomError> {
+    prefix("oklch")
+        .ignore_then(css_arguments(signed_decimal(), 3))
+        .then_ignore(end())
+        .try_map(|oklch, span| {
+            Oklch::try_from(&oklch[..]).map_err(|err| CustomError {
+                msg: String::from("Invalid Oklch value. Expected"),
+                span: oklch.span_of(&err, span),
+                expected: vec![String::from(
+                    "Values: 0-1 for lightness, non-negative chroma, hue in degrees",
+                )],
+                found: vec![err.to_string()],
+                kind: ErrorKind::OutOfRange,
+            })
+        })
+}
+
+pub(crate) fn scrgb() -> impl Parser<char, Scrgb, Error = CustomError> {
+    prefix("scrgb")
+        .ignore_then(numbers_separated_by(signed_decimal(), 3, ','))
+        .then_ignore(just(')'))
+        .then_ignore(end())
+        .try_map(|scrgb, span| {
+            Scrgb::try_from(&scrgb[..]).map_err(|err| CustomError {
+                msg: String::from("Invalid scRGB value. Expected"),
+                span: scrgb.span_of(&err, span),
+                expected: vec![String::from(
+                    "Linear light values, e.g. scrgb(1.8, 0.2, -0.05)",
+                )],
+                found: vec![err.to_string()],
+                kind: ErrorKind::OutOfRange,
+            })
+        })
+}
+
+/// Parses HDR color channels with optional peak luminance in nits, e.g.
+/// `(0.58, 0.58, 0.58)` or `(0.58, 0.58, 0.58, 203)`.
+fn hdr_channels<P>(number: P) -> impl Parser<char, Arguments, Error = CustomError>
+where
+    P: Parser<char, String, Error = CustomError> + Copy,
+{
+    numbers_between(number, 3, 4, ',')
+        .then_ignore(just(')'))
+        .then_ignore(end())
+}
+
+pub(crate) fn pq() -> impl Parser<char, Pq, Error = CustomError> {
+    prefix("pq")
+        .ignore_then(hdr_channels(decimal()))
+        .try_map(|pq, span| {
+            Pq::try_from(&pq[..]).map_err(|err| CustomError {
+                msg: String::from("Invalid PQ value. Expected"),
+                span: pq.span_of(&err, span),
+                expected: vec![String::from(
+                    "Values: 0-1 for encoded channels, optional peak luminance in nits",
+                )],
+                found: vec![err.to_string()],
+                kind: ErrorKind::OutOfRange,
+            })
+        })
+}
+
+pub(crate) fn hlg() -> impl Parser<char, Hlg, Error = CustomError> {
+    prefix("hlg")
+        .ignore_then(hdr_channels(decimal()))
+        .try_map(|hlg, span| {
+            Hlg::try_from(&hlg[..]).map_err(|err| CustomError {
+                msg: String::from("Invalid HLG value. Expected"),
+                span: hlg.span_of(&err, span),
+                expected: vec![String::from(
+                    "Values: 0-1 for encoded channels, optional peak luminance in nits",
+                )],
+                found: vec![err.to_string()],
+                kind: ErrorKind::OutOfRange,
+            })
+        })
+}
+
+pub(crate) fn ictcp() -> impl Parser<char, Ictcp, Error = CustomError> {
+    prefix("ictcp")
+        .ignore_then(hdr_channels(signed_decimal()))
+        .try_map(|ictcp, span| {
+            Ictcp::try_from(&ictcp[..]).map_err(|err| CustomError {
+                msg: String::from("Invalid ICtCp value. Expected"),
+                span: ictcp.span_of(&err, span),
+                expected: vec![String::from(
+                    "Values: 0-1 for I, -0.5-0.5 for Ct and Cp, optional peak luminance in nits",
+                )],
+                found: vec![err.to_string()],
+                kind: ErrorKind::OutOfRange,
+            })
+        })
+}
+
+pub(crate) fn jzazbz() -> impl Parser<char, Jzazbz, Error = CustomError> {
+    prefix("jzazbz")
+        .ignore_then(hdr_channels(signed_decimal()))
+        .try_map(|jzazbz, span| {
+            Jzazbz::try_from(&jzazbz[..]).map_err(|err| CustomError {
+                msg: String::from("Invalid Jzazbz value. Expected"),
+                span: jzazbz.span_of(&err, span),
+                expected: vec![String::from(
+                    "Values: 0-1 for Jz, -0.5-0.5 for az and bz, optional peak luminance in nits",
+                )],
+                found: vec![err.to_string()],
+                kind: ErrorKind::OutOfRange,
+            })
+        })
+}
+
+pub(crate) fn jzczhz() -> impl Parser<char, Jzczhz, Error = CustomError> {
+    prefix("jzczhz")
+        .ignore_then(hdr_channels(decimal()))
+        .try_map(|jzczhz, span| {
+            Jzczhz::try_from(&jzczhz[..]).map_err(|err| CustomError {
+                msg: String::from("Invalid JzCzhz value. Expected"),
+                span: jzczhz.span_of(&err, span),
+                expected: vec![String::from(
+                    "Values: 0-1 for Jz, 0-0.5 for Cz, hue in degrees, optional peak luminance in nits",
+                )],
+                found: vec![err.to_string()],
+                kind: ErrorKind::OutOfRange,
+            })
+        })
+}
+
+/// Parses CSS `color()` function with RGB space known to the configuration,
+/// e.g. `color(display-p3 0.2 0.4 0.8)`.
+fn parse_space_color(config: Config) -> impl Parser<char, Color, Error = CustomError> {
+    let name = filter(|c: &char| is_name_char(*c))
+        .repeated()
+        .at_least(1)
+        .map(|name| name.iter().collect::<String>());
+
+    prefix("color")
+        .ignore_then(name.padded())
+        .then(css_arguments(signed_decimal(), 3))
+        .then_ignore(end())
+        .try_map(move |(name, values), span| {
+            let space = config.space(&name).ok_or_else(|| CustomError {
+                msg: String::from("Unknown color space. Expected"),
+                span: span.clone(),
+                expected: config
+                    .spaces()
+                    .iter()
+                    .map(|space| space.name().to_string())
+                    .collect(),
+                found: vec![name.clone()],
+                kind: ErrorKind::UnknownSpace(name.clone()),
+            })?;
+
+            SpaceRgb::try_from_values(space, &values, config.adaptation())
+                .map(Color::from)
+                .map_err(|err| CustomError {
+                    msg: String::from("Invalid color value. Expected"),
+                    span: values.span_of(&err, span),
+                    expected: vec![String::from(
+                        "Channel values of the color space, e.g. color(display-p3 0.2 0.4 0.8)",
+                    )],
+                    found: vec![err.to_string()],
+                    kind: ErrorKind::OutOfRange,
+                })
+        })
+}
+
+/// Parses LaTeX `xcolor` color definition, e.g.
+/// `\definecolor{brand}{RGB}{51,102,204}` or `\definecolor{brand}{HTML}{3366CC}`.
+fn parse_latex() -> impl Parser<char, Color, Error = CustomError> {
+    let name = none_of("}").repeated().delimited_by(just('{'), just('}'));
+
+    let rgb_int = just("{RGB}")
+        .ignore_then(arguments(byte(), 3, '{', '}'))
+        .map(|rgb| Color::from_rgba(rgb[0], rgb[1], rgb[2], u8::MAX));
+
+    let rgb = just("{rgb}")
+        .ignore_then(arguments(unit_float(), 3, '{', '}'))
+        .map(|rgb| from_unit_channels(&rgb));
+
+    let html = just("{HTML}")
+        .ignore_then(
+            hex_number("", 6)
+                .padded()
+                .delimited_by(just('{'), just('}')),
+        )
+        .map(|rgb| Color::from_rgba(rgb[0], rgb[1], rgb[2], u8::MAX));
+
+    let cmyk = just("{cmyk}")
+        .ignore_then(arguments(unit_float(), 4, '{', '}'))
+        .map(|cmyk| {
+            let channel = |c: f64| (1.0 - c) * (1.0 - cmyk[3]);
+            Color::from_unit_rgba(channel(cmyk[0]), channel(cmyk[1]), channel(cmyk[2]), 1.0)
+        });
+
+    let gray = just("{gray}")
+        .ignore_then(arguments(unit_float(), 1, '{', '}'))
+        .map(|gray| Color::from_unit_rgba(gray[0], gray[0], gray[0], 1.0));
+
+    choice((just("\\definecolor"), just("\\providecolor")))
+        .ignore_then(name)
+        .ignore_then(choice((rgb_int, rgb, html, cmyk, gray)))
+        .then_ignore(end())
+}
+
+/// Parses Flutter or Jetpack Compose color literal, e.g. `Color(0xFF3366CC)`.
+fn parse_argb_literal() -> impl Parser<char, Color, Error = CustomError> {
+    just("Color")
+        .ignore_then(
+            hex_number("0x", 8)
+                .padded()
+                .delimited_by(just('('), just(')')),
+        )
+        .then_ignore(end())
+        .map(|argb| Color::from_rgba(argb[1], argb[2], argb[3], argb[0]))
+}
+
+/// Parses named floating point channels with optional alpha, e.g.
+/// `red: 0.2, green: 0.4, blue: 0.8, alpha: 1`.
+fn named_channels(alpha: &'static str) -> impl Parser<char, Color, Error = CustomError> {
+    named("red", unit_float())
+        .padded()
+        .then_ignore(just(','))
+        .then(named("green", unit_float()).padded())
+        .then_ignore(just(','))
+        .then(named("blue", unit_float()).padded())
+        .then(
+            just(',')
+                .ignore_then(named(alpha, unit_float()).padded())
+                .or_not(),
+        )
+        .map(|(((red, green), blue), alpha)| {
+            Color::from_unit_rgba(red, green, blue, alpha.unwrap_or(1.0))
+        })
+}
+
+/// Parses SwiftUI color literal, e.g. `Color(red: 0.2, green: 0.4, blue: 0.8)`.
+fn parse_swiftui_literal() -> impl Parser<char, Color, Error = CustomError> {
+    just("Color(")
+        .ignore_then(named_channels("opacity"))
+        .then_ignore(just(')'))
+        .then_ignore(end())
+}
+
+/// Parses UIKit color literal, e.g.
+/// `UIColor(red: 0.2, green: 0.4, blue: 0.8, alpha: 1)`.
+fn parse_uikit_literal() -> impl Parser<char, Color, Error = CustomError> {
+    just("UIColor(")
+        .ignore_then(named_channels("alpha"))
+        .then_ignore(just(')'))
+        .then_ignore(end())
+}
+
+/// Parses GLSL vector literal, e.g. `vec3(0.2, 0.4, 0.8)` or
+/// `vec4(0.2, 0.4, 0.8, 1.0)`.
+fn parse_glsl_literal() -> impl Parser<char, Color, Error = CustomError> {
+    let vec3 = just("vec3").ignore_then(arguments(unit_float(), 3, '(', ')'));
+    let vec4 = just("vec4").ignore_then(arguments(unit_float(), 4, '(', ')'));
+
+    choice((vec3, vec4))
+        .then_ignore(end())
+        .map(|channels| from_unit_channels(&channels))
+}
+
+/// Parses Unity color literal, e.g. `new Color(0.2f, 0.4f, 0.8f)` or
+/// `new Color32(51, 102, 204, 255)`.
+fn parse_unity_literal() -> impl Parser<char, Color, Error = CustomError> {
+    let color32 = just("Color32")
+        .ignore_then(arguments(byte(), 4, '(', ')'))
+        .map(|rgba| Color::from_rgba(rgba[0], rgba[1], rgba[2], rgba[3]));
+
+    let color = just("Color")
+        .ignore_then(choice((
+            arguments(unit_float(), 4, '(', ')'),
+            arguments(unit_float(), 3, '(', ')'),
+        )))
+        .map(|channels| from_unit_channels(&channels));
+
+    just("new")
+        .then(whitespace())
+        .ignore_then(choice((color32, color)))
+        .then_ignore(end())
+}
+
+/// Parses Android XML color resource, e.g.
+/// `<color name="brand">#FF3366CC</color>`.
+fn parse_android_literal() -> impl Parser<char, Color, Error = CustomError> {
+    let argb = hex_number("#", 8).map(|argb| Color::from_rgba(argb[1], argb[2], argb[3], argb[0]));
+    let rgb = hex_number("#", 6).map(|rgb| Color::from_rgba(rgb[0], rgb[1], rgb[2], u8::MAX));
+
+    just("<color")
+        .then(none_of(">").repeated())
+        .then(just('>'))
+        .ignore_then(choice((argb, rgb)).padded())
+        .then_ignore(just("</color>"))
+        .then_ignore(end())
+}
+
+/// Parses Rust tuple or struct literal, e.g. `(51, 102, 204)` or
+/// `Rgb { r: 51, g: 102, b: 204 }`.
+fn parse_rust_literal() -> impl Parser<char, Color, Error = CustomError> {
+    let tuple = arguments(byte(), 3, '(', ')');
+
+    let fields = named("r", byte())
+        .padded()
+        .then_ignore(just(','))
+        .then(named("g", byte()).padded())
+        .then_ignore(just(','))
+        .then(named("b", byte()).padded())
+        .delimited_by(just('{'), just('}'))
+        .map(|((red, green), blue)| vec![red, green, blue]);
+
+    let structure = just("Rgb").then(whitespace()).ignore_then(fields);
+
+    choice((tuple, structure))
+        .then_ignore(end())
+        .map(|rgb| Color::from_rgba(rgb[0], rgb[1], rgb[2], u8::MAX))
+}
+
+/// Parses C initializer, e.g. `{51, 102, 204}`.
+fn parse_c_literal() -> impl Parser<char, Color, Error = CustomError> {
+    arguments(byte(), 3, '{', '}')
+        .then_ignore(end())
+        .map(|rgb| Color::from_rgba(rgb[0], rgb[1], rgb[2], u8::MAX))
+}
+
+/// Names of functions known to the parser, including literals of programming
+/// languages, compared case-insensitively.
+const FUNCTIONS: [&str; 20] = [
+    "rgb", "rgb48", "hsl", "hsv", "xyz", "xyy", "lab", "lch", "oklab", "oklch", "scrgb", "pq",
+    "hlg", "ictcp", "jzazbz", "jzczhz", "color", "uicolor", "vec3", "vec4",
+];
+
+/// Fails with an error naming the function for input like `hwb(...)`, where
+/// the function is not one of `known`, whose parsers report their own errors.
+fn parse_unknown_function<O>(known: &[&'static str]) -> impl Parser<char, O, Error = CustomError> {
+    let expected = known
+        .iter()
+        .map(|name| format!("{name}("))
+        .collect::<Vec<_>>();
+    let known = known.to_vec();
+
+    filter(|c: &char| c.is_alphanumeric() || *c == '-' || *c == '_')
+        .repeated()
+        .at_least(1)
+        .collect::<String>()
+        .try_map(move |name, span| {
+            if known.contains(&name.to_lowercase().as_str()) {
+                // Let the parser of the function report the error.
+                Err(CustomError {
+                    span,
+                    ..CustomError::default()
+                })
+            } else {
+                Ok((name, span))
+            }
+        })
+        .then_ignore(just('('))
+        .try_map(move |(name, name_span), _| {
+            Err(CustomError {
+                msg: String::from("Unknown function. Expected"),
+                span: name_span,
+                expected: expected.clone(),
+                found: vec![name.clone()],
+                kind: ErrorKind::UnknownFunction(name),
+            })
+        })
+}
+
+/// Creates color from 3 or 4 floating point channels, where the optional
+/// fourth channel is alpha.
+fn from_unit_channels(channels: &[f64]) -> Color {
+    let alpha = channels.get(3).copied().unwrap_or(1.0);
+
+    Color::from_unit_rgba(channels[0], channels[1], channels[2], alpha)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::ParseError,
+        format::{Hsl, Jzazbz, Jzczhz, Lab, Lch, Oklch, Pq, Rgb},
+        AlphaPosition, Color, Config, Format, GamutMapping, HexOptions, Options, WhitePoint,
+    };
+
+    use std::str::FromStr;
+
+    use super::{parse_color, parse_color_with};
+
+    #[test]
+    fn hex_alpha_position() {
+        let color = Color::from_rgba(51, 102, 204, 128);
+        let first = Config::default().with_hex_alpha(AlphaPosition::First);
+
+        assert_eq!(parse_color("3366cc80"), Ok(color));
+        assert_eq!(parse_color_with("803366cc", &first), Ok(color));
+        assert_eq!(parse_color_with("#836c", &first), parse_color("#36c8"));
+        assert_eq!(parse_color_with("3366cc", &first), parse_color("3366cc"));
+
+        // Output with alpha first is read back with the same option.
+        let options = Options {
+            hex: HexOptions {
+                alpha: AlphaPosition::First,
+                ..HexOptions::default()
+            },
+            ..Options::default()
+        };
+        let output = color.convert_with(Format::Hex, &options).unwrap();
+
+        assert_eq!(output, "803366cc");
+        assert_eq!(parse_color_with(&output, &first), Ok(color));
+    }
+
+    #[test]
+    fn literals() {
+        let expected = Color::from_rgba(51, 102, 204, 255);
+
+        for input in [
+            "Color(0xFF3366CC)",
+            "Color(red: 0.2, green: 0.4, blue: 0.8)",
+            "UIColor(red: 0.2, green: 0.4, blue: 0.8, alpha: 1)",
+            "vec3(0.2, 0.4, 0.8)",
+            "vec4(0.2, 0.4, 0.8, 1.0)",
+            "new Color(0.2f, 0.4f, 0.8f)",
+            "new Color32(51, 102, 204, 255)",
+            "<color name=\"brand\">#FF3366CC</color>",
+            "(51, 102, 204)",
+            "Rgb { r: 51, g: 102, b: 204 }",
+            "{51, 102, 204}",
+            "\\definecolor{brand}{RGB}{51,102,204}",
+            "\\definecolor{brand}{rgb}{0.2,0.4,0.8}",
+            "\\definecolor{brand}{HTML}{3366CC}",
+            "\\definecolor{brand}{cmyk}{0.75,0.5,0,0.2}",
+            "\\providecolor{brand}{RGB}{51, 102, 204}",
+            "#3366cc",
+            "0x3366CC",
+            "#36c",
+            "3366ccff",
+        ] {
+            assert_eq!(parse_color(input), Ok(expected), "input: {input}");
+        }
+    }
+
+    #[test]
+    fn latex_gray() {
+        assert_eq!(
+            parse_color("\\definecolor{mid}{gray}{0.5}"),
+            Ok(Color::from_unit_rgba(0.5, 0.5, 0.5, 1.0))
+        );
+    }
+
+    #[test]
+    fn deep_color() {
+        for input in ["#00018000ffff", "rgb48(1, 32768, 65535)"] {
+            let color = parse_color(input).unwrap();
+
+            assert_eq!(
+                color.convert(Format::Rgb48).unwrap(),
+                "rgb48(1, 32768, 65535)",
+                "input: {input}"
+            );
+        }
+
+        let color = parse_color("#fff000fff").unwrap();
+        assert_eq!(
+            color.convert(Format::Rgb48).unwrap(),
+            "rgb48(65535, 0, 65535)"
+        );
+    }
+
+    #[test]
+    fn space_color() {
+        for input in [
+            "color(srgb 0.2 0.4 0.8)",
+            "color(display-p3 0.2499, 0.3952, 0.7736)",
+            "COLOR( rec2020 0.2739 0.3416 0.7428 )",
+        ] {
+            let color = parse_color(input).unwrap();
+
+            assert_eq!(
+                color.convert(Format::Hex).unwrap(),
+                "3366cc",
+                "input: {input}"
+            );
+        }
+
+        assert!(parse_color("color(unknown 0.2 0.4 0.8)").is_err());
+    }
+
+    #[test]
+    fn configured_space_color() {
+        let config = Config::from_toml(
+            "[space.monitor]\nred = [0.68, 0.32]\ngreen = [0.265, 0.69]\nblue = [0.15, 0.06]\ntransfer = \"srgb\"",
+        )
+        .unwrap();
+
+        let color = parse_color_with("color(monitor 0.2499 0.3952 0.7736)", &config).unwrap();
+
+        assert_eq!(color.convert(Format::Hex).unwrap(), "3366cc");
+    }
+
+    #[test]
+    fn xyz_white_point() {
+        let d50 = Config::default().with_white_point(WhitePoint::D50);
+
+        let white = parse_color_with("xyz(0.9642, 1.0, 0.8251)", &d50).unwrap();
+        assert_eq!(white.convert(Format::Hex).unwrap(), "ffffff");
+
+        let color = parse_color_with("xyy(0.2066, 0.1892, 0.1392)", &d50).unwrap();
+        assert_eq!(color.convert(Format::Hex).unwrap(), "3366cc");
+
+        let color = parse_color("xyz(0.1701, 0.1457, 0.5904)").unwrap();
+        assert_eq!(color.convert(Format::Hex).unwrap(), "3366cc");
+    }
+
+    #[test]
+    fn oklab() {
+        for input in [
+            "oklab(0.5325, -0.0225, -0.1663)",
+            "oklch(0.5325 0.1679 262.293)",
+        ] {
+            let color = parse_color(input).unwrap();
+
+            assert_eq!(
+                color.convert(Format::Hex).unwrap(),
+                "3366cc",
+                "input: {input}"
+            );
+        }
+    }
+
+    #[test]
+    fn gamut_mapping() {
+        let color = parse_color("oklch(0.9 0.3 145)").unwrap();
+        let (clipped, mapped) = color.map_to_gamut(GamutMapping::Clip);
+
+        assert!(mapped);
+        assert_eq!(clipped.convert(Format::Rgb).unwrap(), "rgb(0, 255, 59)");
+        assert_eq!(color.convert(Format::Rgb).unwrap(), "rgb(83, 255, 105)");
+
+        let in_gamut = parse_color("#3366cc").unwrap();
+        assert!(!in_gamut.map_to_gamut(GamutMapping::Css).1);
+    }
+
+    #[test]
+    fn hdr_perceptual() {
+        for input in [
+            "ictcp(0.5534, 0.1645, -0.1036)",
+            "jzazbz(0.20506, -0.04131, -0.14962)",
+            "jzczhz(0.20506, 0.15522, 254.56499, 1000)",
+        ] {
+            let color = parse_color(input).unwrap();
+
+            assert_eq!(
+                color.convert(Format::Hex).unwrap(),
+                "3366cc",
+                "input: {input}"
+            );
+        }
+    }
+
+    #[test]
+    fn hex_alpha() {
+        assert_eq!(
+            parse_color("#3366cc80"),
+            Ok(Color::from_rgba(51, 102, 204, 128))
+        );
+        assert_eq!(
+            parse_color("#36c8"),
+            Ok(Color::from_rgba(51, 102, 204, 136))
+        );
+    }
+
+    #[test]
+    fn literal_alpha() {
+        assert_eq!(
+            parse_color("Color(0x803366CC)"),
+            Ok(Color::from_rgba(51, 102, 204, 128))
+        );
+    }
+
+    #[test]
+    fn literal_error_points_at_argument() {
+        let errors = parse_color("vec3(0.2, 1.4, 0.8)").unwrap_err();
+
+        assert_eq!(errors[0].span, 10..13);
+        assert_eq!(errors[0].found, vec![String::from("1.4")]);
+    }
+
+    #[test]
+    fn range_error_points_at_argument() {
+        let errors = parse_color("hsl(220, 160, 50)").unwrap_err();
+        assert_eq!(errors[0].span, 9..12);
+
+        let errors = parse_color("hsl(220, 60%, 150%)").unwrap_err();
+        assert_eq!(errors[0].span, 14..18);
+
+        let errors = parse_color("oklch(0.5 -0.1 120)").unwrap_err();
+        assert_eq!(errors[0].span, 10..14);
+    }
+
+    #[test]
+    fn structured_errors() {
+        let error = |input: &str| input.parse::<Color>().unwrap_err();
+
+        assert!(matches!(
+            error("hwb(50, 20, 30)"),
+            ParseError::UnknownFunction { name, span } if name == "hwb" && span == (0..3)
+        ));
+        assert!(matches!(
+            error("rgb(1, 2, 3, 4)"),
+            ParseError::WrongArity { found: 4, .. }
+        ));
+        assert!(matches!(
+            error("rgb(300, 2, 3)"),
+            ParseError::OutOfRange { .. }
+        ));
+        assert!(matches!(
+            error("color(cmyk 1 2 3)"),
+            ParseError::UnknownSpace { name, .. } if name == "cmyk"
+        ));
+    }
+
+    #[test]
+    fn from_str() {
+        let color = Color::from_rgba(51, 102, 204, 128);
+        assert_eq!(color.to_string().parse::<Color>(), Ok(color));
+
+        let hsl = "hsl(220, 60%, 50%)".parse::<Hsl>().unwrap();
+        assert_eq!(hsl.to_string(), "hsl(220, 60, 50)");
+
+        let oklch = "oklch(0.5 0.1 120)".parse::<Oklch>().unwrap();
+        assert_eq!(oklch.hue(), 120.0);
+
+        let jzazbz = "jzazbz(0.1, 0, 0, 203)".parse::<Jzazbz>().unwrap();
+        assert_eq!(jzazbz.peak(), 203.0);
+
+        assert!(matches!(
+            "hsv(220, 60, 50)".parse::<Hsl>(),
+            Err(ParseError::UnknownFunction { .. })
+        ));
+        assert!(matches!(
+            "hsl(220, 60)".parse::<Hsl>(),
+            Err(ParseError::WrongArity { found: 2, .. })
+        ));
+        assert!(matches!(
+            "hsl(220, 160, 50)".parse::<Hsl>(),
+            Err(ParseError::OutOfRange { span, .. }) if span == (9..12)
+        ));
+    }
+
+    #[test]
+    fn from_str_agrees_with_color() {
+        // Formats are parsed with the same grammar as colors, so either both
+        // accept input, giving the same color, or both reject it with the same
+        // kind of error. Expected input differs, as colors have more formats.
+        fn agree<S>(input: &str)
+        where
+            S: FromStr<Err = ParseError> + Into<Color> + core::fmt::Debug,
+        {
+            match (input.parse::<S>(), input.parse::<Color>()) {
+                (Ok(parsed), Ok(color)) => assert_eq!(parsed.into(), color, "{input}"),
+                (Err(parsed), Err(color)) => assert_eq!(
+                    core::mem::discriminant(&parsed),
+                    core::mem::discriminant(&color),
+                    "{input}: {parsed:?} and {color:?}"
+                ),
+                (parsed, color) => panic!("{input}: {parsed:?} and {color:?}"),
+            }
+        }
+
+        for input in [
+            "hsl(220, 60%, 50%)",
+            "hsl(220 60 50)",
+            "hsl(-20, 60, 50)",
+            "hsl()",
+            "hsl(220, 160, 50)",
+        ] {
+            agree::<Hsl>(input);
+        }
+
+        agree::<Rgb>("rgb(51, 102, 204)");
+        agree::<Rgb>("rgb(51 102 204)");
+        agree::<Lab>("lab(54.29 80.8 69.89)");
+        agree::<Lch>("lch(54.29, 106.84, 40.85)");
+        agree::<Lch>("lch(50, -1, 40)");
+        agree::<Oklch>("oklch(0.5 0.1 120)");
+        agree::<Pq>("pq(0.58, 0.58, 0.58, 203)");
+        agree::<Jzczhz>("jzczhz(0.1, 0.05, 270)");
+
+        assert!(matches!(
+            "hsl()".parse::<Hsl>(),
+            Err(ParseError::Syntax { expected, found: Some(found), span })
+                if expected == ["number"] && found == ")" && span == (4..5)
+        ));
+        assert_eq!(
+            "lab(50, 20, 30)".parse::<Color>(),
+            Ok(Color::from(Lab::from((50.0, 20.0, 30.0))))
+        );
+    }
+}
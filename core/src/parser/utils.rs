@@ -0,0 +1,281 @@
+use std::ops::{Deref, Range};
+
+use chumsky::{
+    primitive::{filter, just},
+    text::{whitespace, TextParser},
+    Parser,
+};
+
+use crate::error::{ConversionError, CustomError, ErrorKind};
+
+/// Numeric arguments of a function with their spans in the input.
+#[derive(Debug, Clone, Default)]
+pub struct Arguments {
+    values: Vec<String>,
+    spans: Vec<Range<usize>>,
+}
+
+impl Arguments {
+    /// Returns span of the argument with the value reported by `error`, or
+    /// `span` of the whole color if the error is not caused by one argument.
+    pub fn span_of(&self, error: &ConversionError, span: Range<usize>) -> Range<usize> {
+        let ConversionError::OutOfRange { value, .. } = error else {
+            return span;
+        };
+
+        self.values
+            .iter()
+            .position(|argument| argument.trim_end_matches('%').parse() == Ok(*value))
+            .map_or(span, |index| self.spans[index].clone())
+    }
+}
+
+impl Deref for Arguments {
+    type Target = [String];
+
+    fn deref(&self) -> &Self::Target {
+        &self.values
+    }
+}
+
+impl FromIterator<(String, Range<usize>)> for Arguments {
+    fn from_iter<I: IntoIterator<Item = (String, Range<usize>)>>(iter: I) -> Self {
+        let (values, spans) = iter.into_iter().unzip();
+
+        Self { values, spans }
+    }
+}
+
+pub fn prefix(prefix: &str) -> impl Parser<char, String, Error = CustomError> + '_ {
+    filter(|input: &char| input.is_alphanumeric())
+        .repeated()
+        .exactly(prefix.len())
+        .map(|input| input.iter().collect::<String>())
+        .then(just("("))
+        .try_map(move |(rgb, parenth), span| {
+            if rgb.to_lowercase() != prefix || parenth != "(" {
+                Err(CustomError {
+                    msg: String::from("Unexpected input. Expected"),
+                    span,
+                    expected: vec![format!("{prefix}(")],
+                    found: vec![rgb, parenth.to_string()],
+                    kind: ErrorKind::Syntax,
+                })
+            } else {
+                Ok(rgb)
+            }
+        })
+}
+
+pub fn digit(radix: u32) -> impl Parser<char, char, Error = CustomError> + Copy {
+    filter(move |input: &char| input.is_digit(radix))
+}
+
+pub fn n_digits(n: usize, radix: u32) -> impl Parser<char, String, Error = CustomError> + Copy {
+    digit(radix)
+        .repeated()
+        .at_least(1)
+        .at_most(n)
+        .map(|input| input.iter().collect::<String>())
+}
+
+/// Parses a decimal number with optional fractional part, e.g. `1`, `0.3127`.
+pub fn decimal() -> impl Parser<char, String, Error = CustomError> + Copy {
+    digit(10)
+        .repeated()
+        .at_least(1)
+        .chain::<char, _, _>(just('.').chain(digit(10).repeated().at_least(1)).or_not())
+        .map(|input| input.iter().collect::<String>())
+}
+
+/// Parses a decimal number with optional minus sign, e.g. `-0.05`.
+pub fn signed_decimal() -> impl Parser<char, String, Error = CustomError> + Copy {
+    just('-')
+        .or_not()
+        .then(decimal())
+        .map(|(sign, number)| sign.into_iter().chain(number.chars()).collect())
+}
+
+pub fn numbers_separated_by<P>(
+    digits_parser: P,
+    n: usize,
+    separator: char,
+) -> impl Parser<char, Arguments, Error = CustomError> + Copy
+where
+    P: Parser<char, String, Error = CustomError>,
+    P: Copy,
+{
+    numbers_between(digits_parser, n, n, separator)
+}
+
+/// Parses at least `min` and at most `max` numbers separated by `separator`.
+pub fn numbers_between<P>(
+    digits_parser: P,
+    min: usize,
+    max: usize,
+    separator: char,
+) -> impl Parser<char, Arguments, Error = CustomError> + Copy
+where
+    P: Parser<char, String, Error = CustomError>,
+    P: Copy,
+{
+    digits_parser
+        .map_err(expect_number)
+        .map_with_span(|number, span| (number, span))
+        .separated_by(just(separator).then_ignore(whitespace()))
+        .at_least(1)
+        .try_map(move |numbers, span| arity(numbers, min, max, span))
+        .collect()
+}
+
+/// Parses `n` numbers separated by commas or whitespace and followed by closing
+/// parenthesis, as in CSS, e.g. `0.6 0.1 250)` or `0.6, 0.1, 250)`.
+pub fn css_arguments<P>(
+    number_parser: P,
+    n: usize,
+) -> impl Parser<char, Arguments, Error = CustomError> + Clone
+where
+    P: Parser<char, String, Error = CustomError> + Clone,
+{
+    number_parser
+        .map_err(expect_number)
+        .map_with_span(|number, span| (number, span))
+        .separated_by(just(',').or_not().padded())
+        .at_least(1)
+        .padded()
+        .try_map(move |numbers, span| arity(numbers, n, n, span))
+        .collect()
+        .then_ignore(just(')'))
+}
+
+/// Names number as expected input, e.g. for `hsl()`, where the parser of
+/// digits expects no particular character.
+fn expect_number(error: CustomError) -> CustomError {
+    if error.expected.is_empty() {
+        CustomError {
+            expected: vec![String::from("number")],
+            ..error
+        }
+    } else {
+        error
+    }
+}
+
+/// Checks that number of arguments is in range `min..=max`.
+fn arity<T>(
+    arguments: Vec<T>,
+    min: usize,
+    max: usize,
+    span: Range<usize>,
+) -> Result<Vec<T>, CustomError> {
+    let found = arguments.len();
+
+    if (min..=max).contains(&found) {
+        return Ok(arguments);
+    }
+
+    let expected = if min == max {
+        format!("{min} arguments")
+    } else {
+        format!("{min} to {max} arguments")
+    };
+
+    Err(CustomError {
+        msg: String::from("Wrong number of arguments. Expected"),
+        span,
+        expected: vec![expected],
+        found: vec![format!("{found} arguments")],
+        kind: ErrorKind::WrongArity {
+            expected: min..=max,
+            found,
+        },
+    })
+}
+
+/// Parses a color channel given as integer in range 0..255.
+pub fn byte() -> impl Parser<char, u8, Error = CustomError> + Copy {
+    n_digits(3, 10).try_map(|value, span| {
+        value.parse::<u8>().map_err(|_| CustomError {
+            msg: String::from("Invalid channel value. Expected"),
+            span,
+            expected: vec![String::from("Value in range 0-255")],
+            found: vec![value],
+            kind: ErrorKind::OutOfRange,
+        })
+    })
+}
+
+/// Parses a color channel given as floating point number in range 0..1, with
+/// optional `f` suffix as used in C-like languages, e.g. `0.2` or `0.2f`.
+pub fn unit_float() -> impl Parser<char, f64, Error = CustomError> + Copy {
+    decimal()
+        .then_ignore(just('f').or_not())
+        .try_map(|value, span| match value.parse::<f64>() {
+            Ok(channel) if (0.0..=1.0).contains(&channel) => Ok(channel),
+            _ => Err(CustomError {
+                msg: String::from("Invalid channel value. Expected"),
+                span,
+                expected: vec![String::from("Value in range 0-1")],
+                found: vec![value],
+                kind: ErrorKind::OutOfRange,
+            }),
+        })
+}
+
+/// Parses a hexadecimal number with exactly `n` digits, optionally prefixed
+/// with `prefix`, e.g. `0xFF3366CC` or `#3366CC`.
+pub fn hex_number(
+    prefix: &'static str,
+    n: usize,
+) -> impl Parser<char, Vec<u8>, Error = CustomError> + Clone {
+    just(prefix)
+        .ignore_then(digit(16).repeated().at_least(1))
+        .try_map(move |digits, span| {
+            if digits.len() != n {
+                return Err(CustomError {
+                    msg: String::from("Invalid hexadecimal number. Expected"),
+                    span,
+                    expected: vec![format!("{n} hex digits")],
+                    found: vec![digits.into_iter().collect()],
+                    kind: ErrorKind::Syntax,
+                });
+            }
+
+            Ok(digits
+                .chunks(2)
+                .map(|pair| {
+                    let pair = pair.iter().collect::<String>();
+                    u8::from_str_radix(&pair, 16).expect("digits are hexadecimal")
+                })
+                .collect())
+        })
+}
+
+/// Parses argument named `name`, e.g. `red: 0.2`.
+pub fn named<P, O>(
+    name: &'static str,
+    argument: P,
+) -> impl Parser<char, O, Error = CustomError> + Clone
+where
+    P: Parser<char, O, Error = CustomError> + Clone,
+{
+    just(name).then(just(':').padded()).ignore_then(argument)
+}
+
+/// Parses `n` comma separated arguments wrapped in `open` and `close`
+/// delimiters, e.g. `(0.2, 0.4, 0.8)`.
+pub fn arguments<P, O>(
+    argument: P,
+    n: usize,
+    open: char,
+    close: char,
+) -> impl Parser<char, Vec<O>, Error = CustomError> + Clone
+where
+    P: Parser<char, O, Error = CustomError> + Clone,
+{
+    argument
+        .padded()
+        .separated_by(just(','))
+        .exactly(n)
+        .delimited_by(just(open), just(close))
+}
@@ -0,0 +1,309 @@
+//! Serialization of colors with [serde](https://serde.rs).
+//!
+//! [`Color`] and all formats are serialized in their textual representation by
+//! default, e.g. `"3366cc"` for [`Color`] or
+//! `"oklch(0.5325, 0.1679, 262.2930)"` for [`Oklch`], and deserialized from any
+//! input accepted by the parser of the type.
+//!
+//! The structured form with named components, e.g.
+//! `{"r": 51, "g": 102, "b": 204}`, is selected with
+//! `#[serde(with = "boja_core::serde::structured")]`:
+//!
+//! ```
+//! use boja_core::{format::Oklch, Color};
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Palette {
+//!     primary: Color,
+//!     #[serde(with = "boja_core::serde::structured")]
+//!     accent: Color,
+//!     #[serde(with = "boja_core::serde::structured")]
+//!     muted: Oklch,
+//! }
+//!
+//! let palette: Palette = serde_json::from_str(
+//!     r##"{
+//!         "primary": "#3366cc",
+//!         "accent": {"r": 51, "g": 102, "b": 204},
+//!         "muted": {"lightness": 0.6, "chroma": 0.05, "hue": 262}
+//!     }"##,
+//! )
+//! .unwrap();
+//!
+//! assert_eq!(palette.primary, palette.accent);
+//! ```
+
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{
+    format::{
+        ColorSpace, Hlg, Hsl, Hsv, Ictcp, Jzazbz, Jzczhz, Lab, Lch, Oklab, Oklch, Pq, Rgb, Rgb48,
+        Scrgb, Xyy, Xyz,
+    },
+    Color,
+};
+
+/// Implements serialization in textual representation.
+macro_rules! string_form {
+    ($($format:ty),+ $(,)?) => {
+        $(
+            impl Serialize for $format {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    string::serialize(self, serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $format {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    string::deserialize(deserializer)
+                }
+            }
+        )+
+    };
+}
+
+string_form!(
+    Color, Hlg, Hsl, Hsv, Ictcp, Jzazbz, Jzczhz, Lab, Lch, Oklab, Oklch, Pq, Rgb, Rgb48, Scrgb,
+    Xyy, Xyz,
+);
+
+/// Textual representation, e.g. `"hsl(220, 60, 50)"`, used by default.
+pub mod string {
+    use super::*;
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Display,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        let input = String::deserialize(deserializer)?;
+
+        input.parse().map_err(D::Error::custom)
+    }
+}
+
+/// Map of named components, e.g. `{"r": 51, "g": 102, "b": 204}` for
+/// [`Color`] or `{"hue": 220, "saturation": 60, "lightness": 50}` for
+/// [`Hsl`]. Unlike the textual representation, components of formats keep
+/// full precision.
+pub mod structured {
+    use super::*;
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Structured,
+        S: Serializer,
+    {
+        value.to_components().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Structured,
+        D: Deserializer<'de>,
+    {
+        let components = BTreeMap::<String, f64>::deserialize(deserializer)?;
+
+        T::from_components(components).map_err(D::Error::custom)
+    }
+}
+
+/// Type with structured form, i.e. [`Color`] and all color spaces.
+pub trait Structured: Sized {
+    fn to_components(&self) -> BTreeMap<&'static str, f64>;
+
+    fn from_components(components: BTreeMap<String, f64>) -> Result<Self, String>;
+}
+
+impl<T: ColorSpace> Structured for T {
+    fn to_components(&self) -> BTreeMap<&'static str, f64> {
+        T::COMPONENTS
+            .iter()
+            .map(|component| component.name)
+            .zip(self.components())
+            .collect()
+    }
+
+    fn from_components(mut components: BTreeMap<String, f64>) -> Result<Self, String> {
+        let mut values = Vec::new();
+
+        for component in T::COMPONENTS {
+            match components.remove(component.name) {
+                Some(value) => values.push(value),
+                None if component.optional => break,
+                None => return Err(format!("missing component `{}`", component.name)),
+            }
+        }
+
+        match components.into_keys().next() {
+            Some(unknown) => Err(format!("unknown component `{unknown}`")),
+            None => T::try_from_components(&values).map_err(|err| err.to_string()),
+        }
+    }
+}
+
+impl Structured for Color {
+    /// Returns channels in range 0..255, alpha only if the color is not
+    /// opaque.
+    fn to_components(&self) -> BTreeMap<&'static str, f64> {
+        let (red, green, blue) = Rgb::from(*self).to_bytes();
+        let alpha = (self.alpha() * 255.0).round();
+
+        let mut components =
+            BTreeMap::from([("r", red as f64), ("g", green as f64), ("b", blue as f64)]);
+
+        if alpha < 255.0 {
+            components.insert("a", alpha);
+        }
+
+        components
+    }
+
+    fn from_components(mut components: BTreeMap<String, f64>) -> Result<Self, String> {
+        let mut channel = |name: &str, default: Option<f64>| {
+            let value = components
+                .remove(name)
+                .or(default)
+                .ok_or_else(|| format!("missing channel `{name}`"))?;
+
+            if !(0.0..=255.0).contains(&value) {
+                return Err(format!("channel `{name}` must be in range 0..255"));
+            }
+
+            Ok(value / 255.0)
+        };
+
+        let color = Color::new(
+            channel("r", None)?,
+            channel("g", None)?,
+            channel("b", None)?,
+            channel("a", Some(255.0))?,
+        );
+
+        match components.into_keys().next() {
+            Some(unknown) => Err(format!("unknown channel `{unknown}`")),
+            None => Ok(color),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        format::{
+            ColorSpace, Hlg, Hsl, Hsv, Ictcp, Jzazbz, Jzczhz, Lab, Lch, Oklab, Oklch, Pq, Rgb,
+            Rgb48, Scrgb, Xyy, Xyz, DEFAULT_PEAK_NITS,
+        },
+        Color,
+    };
+
+    use super::Structured;
+
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Palette {
+        primary: Color,
+        #[serde(with = "super::structured")]
+        accent: Color,
+        #[serde(with = "super::structured")]
+        muted: Hsl,
+    }
+
+    #[test]
+    fn string_form() {
+        let color = Color::from_rgba(51, 102, 204, 128);
+
+        assert_eq!(serde_json::to_string(&color).unwrap(), r#""3366cc80""#);
+        assert_eq!(
+            serde_json::from_str::<Color>(r#""oklch(0.5325 0.1679 262.293)""#)
+                .unwrap()
+                .to_string(),
+            "3366cc"
+        );
+        assert!(serde_json::from_str::<Hsl>(r#""rgb(51, 102, 204)""#).is_err());
+    }
+
+    #[test]
+    fn structured_form() {
+        let palette = Palette {
+            primary: Color::from_rgba(51, 102, 204, 255),
+            accent: Color::from_rgba(51, 102, 204, 128),
+            muted: Hsl::from((220.0, 60.0, 50.0)),
+        };
+
+        let json = serde_json::to_string(&palette).unwrap();
+        assert_eq!(
+            json,
+            r#"{"primary":"3366cc","accent":{"a":128.0,"b":204.0,"g":102.0,"r":51.0},"muted":{"hue":220.0,"lightness":50.0,"saturation":60.0}}"#
+        );
+        assert_eq!(serde_json::from_str::<Palette>(&json).unwrap(), palette);
+    }
+
+    #[test]
+    fn structured_errors() {
+        let structured = |json: &str| {
+            let mut deserializer = serde_json::Deserializer::from_str(json);
+            super::structured::deserialize::<Jzazbz, _>(&mut deserializer)
+        };
+
+        let jzazbz = structured(r#"{"lightness": 0.1, "a": 0, "b": 0}"#).unwrap();
+        assert_eq!(jzazbz.components(), [0.1, 0.0, 0.0, DEFAULT_PEAK_NITS]);
+
+        assert!(structured(r#"{"lightness": 0.1, "a": 0}"#).is_err());
+        assert!(structured(r#"{"lightness": 0.1, "a": 0, "b": 0, "c": 0}"#).is_err());
+
+        // Components are checked like arguments of the function.
+        let hsv = |json: &str| {
+            let mut deserializer = serde_json::Deserializer::from_str(json);
+            super::structured::deserialize::<Hsv, _>(&mut deserializer)
+        };
+
+        let error = hsv(r#"{"hue": 400, "saturation": 150, "value": 50}"#).unwrap_err();
+        assert!(error.to_string().contains("saturation"), "{error}");
+        assert!(hsv(r#"{"hue": 400, "saturation": 50, "value": 50}"#).is_ok());
+    }
+
+    #[test]
+    fn structured_roundtrip() {
+        fn roundtrip<T: ColorSpace>(color: Color) {
+            let value = color.to_space::<T>();
+            let components = Structured::to_components(&value)
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect();
+
+            let parsed = <T as Structured>::from_components(components).unwrap();
+            for (parsed, expected) in parsed.components().into_iter().zip(value.components()) {
+                assert!((parsed - expected).abs() < 1e-9, "{}", T::NAME);
+            }
+        }
+
+        let color = Color::from_rgba(51, 102, 204, 255);
+        roundtrip::<Rgb>(color);
+        roundtrip::<Rgb48>(color);
+        roundtrip::<Hsl>(color);
+        roundtrip::<Hsv>(color);
+        roundtrip::<Scrgb>(color);
+        roundtrip::<Xyz>(color);
+        roundtrip::<Xyy>(color);
+        roundtrip::<Lab>(color);
+        roundtrip::<Lch>(color);
+        roundtrip::<Oklab>(color);
+        roundtrip::<Oklch>(color);
+        roundtrip::<Pq>(color);
+        roundtrip::<Hlg>(color);
+        roundtrip::<Ictcp>(color);
+        roundtrip::<Jzazbz>(color);
+        roundtrip::<Jzczhz>(color);
+    }
+}
@@ -0,0 +1,156 @@
+//! Colors typed by their color space.
+//!
+//! Unlike [`crate::Color`], which is always converted through sRGB at runtime,
+//! [`Color<S>`] keeps the color in space `S`, so values of different spaces
+//! cannot be mixed up, e.g.:
+//!
+//! ```
+//! use boja_core::{
+//!     format::{Lab, Oklch, Rgb},
+//!     typed::{Color, Srgb},
+//! };
+//!
+//! let blue: Color<Srgb> = Color::new(Rgb::from((51, 102, 204)));
+//! let oklch: Color<Oklch> = blue.into_space();
+//!
+//! assert_eq!(oklch.hue().round(), 262.0);
+//! assert_eq!(oklch.into_space::<Srgb>().to_bytes(), (51, 102, 204));
+//!
+//! let lab = Color::<Srgb>::new(Rgb::from((51, 102, 204))).into_space::<Lab>();
+//! assert_eq!(lab.lightness().round(), 44.0);
+//! ```
+//!
+//! Components specific to the space are accessed through getters of the space,
+//! e.g. [`Oklch::hue`](crate::format::Oklch::hue). Conversions call methods of
+//! the spaces directly rather than through [`Graph`](crate::format::Graph),
+//! which passes components in vectors, but the closest space both spaces are
+//! derived from is found at runtime by comparing type ids of their parents.
+
+use core::ops::Deref;
+
+use crate::format::{convert, ColorSpace, Rgb};
+
+/// The sRGB space, hub of all conversions.
+pub type Srgb = Rgb;
+
+/// Color in space `S` with alpha.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Color<S> {
+    color: S,
+    alpha: f64,
+}
+
+impl<S: ColorSpace> Color<S> {
+    /// Creates opaque color.
+    pub fn new(color: S) -> Self {
+        Self::with_alpha(color, 1.0)
+    }
+
+    /// Creates color with alpha in range 0..1, where 1 is fully opaque.
+    pub fn with_alpha(color: S, alpha: f64) -> Self {
+        Self { color, alpha }
+    }
+
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Returns the color without alpha.
+    pub fn into_inner(self) -> S {
+        self.color
+    }
+
+    /// Converts color into space `T`, keeping alpha. The color is converted
+    /// along parents of both spaces, e.g. Lch into Lab directly, and through
+    /// the hub space only if the spaces share no other ancestor.
+    pub fn into_space<T: ColorSpace>(self) -> Color<T> {
+        Color {
+            color: convert(self.color),
+            alpha: self.alpha,
+        }
+    }
+
+    /// Converts color into the parent space of `S`, which is always exact.
+    pub fn into_parent(self) -> Color<S::Parent> {
+        Color {
+            color: self.color.to_parent(),
+            alpha: self.alpha,
+        }
+    }
+}
+
+impl<S> Deref for Color<S> {
+    type Target = S;
+
+    fn deref(&self) -> &S {
+        &self.color
+    }
+}
+
+impl<S: ColorSpace> From<S> for Color<S> {
+    fn from(color: S) -> Self {
+        Self::new(color)
+    }
+}
+
+impl<S: ColorSpace> From<crate::Color> for Color<S> {
+    fn from(color: crate::Color) -> Self {
+        Self {
+            color: color.to_space(),
+            alpha: color.alpha(),
+        }
+    }
+}
+
+impl<S: ColorSpace> From<Color<S>> for crate::Color {
+    fn from(color: Color<S>) -> Self {
+        crate::Color::from_space(color.color).with_alpha(color.alpha)
+    }
+}
+
+impl<S: ColorSpace> core::fmt::Display for Color<S> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.color.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::format::{Hsl, Lab, Lch, Oklab, Oklch, Rgb, Xyz};
+
+    use super::{Color, Srgb};
+
+    #[test]
+    fn into_parent() {
+        let oklch = Color::<Srgb>::new(Rgb::from((51, 102, 204))).into_space::<Oklch>();
+        let oklab: Color<Oklab> = oklch.into_parent();
+
+        assert_eq!(*oklab, Oklab::from(*oklch));
+    }
+
+    #[test]
+    fn into_space() {
+        let lch = Color::<Srgb>::new(Rgb::from((51, 102, 204))).into_space::<Lch>();
+
+        // Lch is converted into Lab and Xyz along its parents, so the values
+        // are exact rather than round tripped through the hub.
+        assert_eq!(*lch.into_space::<Lab>(), Lab::from(*lch));
+        assert_eq!(*lch.into_space::<Xyz>(), Xyz::from(Lab::from(*lch)));
+        assert_eq!(*lch.into_space::<Lch>(), *lch);
+
+        let oklch = lch.into_space::<Oklch>();
+        assert_eq!(oklch.into_space::<Srgb>().to_bytes(), (51, 102, 204));
+    }
+
+    #[test]
+    fn keeps_alpha() {
+        let dynamic = crate::Color::from_rgba(51, 102, 204, 128);
+        let hsl = Color::<Hsl>::from(dynamic);
+
+        assert_eq!(hsl.alpha(), dynamic.alpha());
+        assert_eq!(
+            crate::Color::from(hsl).convert(crate::Format::Hex).unwrap(),
+            "3366cc80"
+        );
+    }
+}
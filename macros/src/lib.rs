@@ -0,0 +1,138 @@
+//! Color literals of [boja](https://docs.rs/boja) validated at compile time.
+//!
+//! [`color!`] parses a color with the parser of boja while the crate using it
+//! is compiled, and expands to a constant expression creating
+//! `boja::Color`, e.g.:
+//!
+//! ```
+//! use boja::Color;
+//! use boja_macros::color;
+//!
+//! const BLUE: Color = color!("#3366cc");
+//! const ACCENT: Color = color!(oklch(0.6 0.1 250));
+//!
+//! assert_eq!(BLUE, Color::from_hex_u32(0x3366cc));
+//! assert_eq!(ACCENT.to_string(), "4f84ba");
+//! ```
+//!
+//! Invalid colors are reported as compile errors with the same messages as
+//! the `boja` command line tool, e.g. `color!(rgb(51, 102))` fails with
+//! "Wrong number of arguments".
+//!
+//! The macro is also re-exported as `boja::color!` with the `macros` feature
+//! of boja. The parser comes from boja-core, which boja re-exports, so that
+//! the re-export is not a cyclic dependency.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Delimiter, Span, TokenStream as TokenStream2, TokenTree};
+use quote::{quote, quote_spanned};
+
+/// Parses color at compile time into `boja::Color`.
+///
+/// The color is given either as string literal, e.g. `color!("#3366cc")`, or
+/// directly as tokens, e.g. `color!(hsl(220, 60%, 50%))`. Any format
+/// accepted by `boja::parse_color` is supported.
+#[proc_macro]
+pub fn color(input: TokenStream) -> TokenStream {
+    let input = Input::new(input.into());
+
+    match boja_core::parse_color(&input.source) {
+        Ok(color) => {
+            let (red, green, blue, alpha) =
+                (color.red(), color.green(), color.blue(), color.alpha());
+
+            quote!(::boja::Color::new(#red, #green, #blue, #alpha)).into()
+        }
+        Err(errors) => {
+            let errors = errors.iter().map(|error| {
+                let message = error.to_string();
+
+                quote_spanned!(input.span_at(error.span.start) =>
+                    ::core::compile_error!(#message)
+                )
+            });
+
+            // The last error is the value of the block, so that no other
+            // error about its type is reported.
+            quote!({ #(#errors);* }).into()
+        }
+    }
+}
+
+/// Textual representation of the color with spans of tokens it was written
+/// from, so that errors point at the invalid part of the input.
+struct Input {
+    source: String,
+    /// Offset in characters of every token in the source, in increasing
+    /// order.
+    spans: Vec<(usize, Span)>,
+}
+
+impl Input {
+    fn new(tokens: TokenStream2) -> Self {
+        if let Ok(literal) = syn::parse2::<syn::LitStr>(tokens.clone()) {
+            return Self {
+                source: literal.value(),
+                spans: vec![(0, literal.span())],
+            };
+        }
+
+        let mut input = Self {
+            source: String::new(),
+            spans: Vec::new(),
+        };
+
+        input.write(tokens);
+        input
+    }
+
+    /// Appends tokens to the source, separating only adjacent words and
+    /// numbers, e.g. `oklch(0.6 0.1 250)` or `#3366cc`.
+    fn write(&mut self, tokens: TokenStream2) {
+        let mut previous_word = false;
+
+        for token in tokens {
+            let word = matches!(token, TokenTree::Ident(_) | TokenTree::Literal(_));
+
+            if word && previous_word {
+                self.source.push(' ');
+            }
+
+            self.spans.push((self.source.chars().count(), token.span()));
+
+            match token {
+                TokenTree::Group(group) => {
+                    let (open, close) = match group.delimiter() {
+                        Delimiter::Parenthesis => ("(", ")"),
+                        Delimiter::Brace => ("{", "}"),
+                        Delimiter::Bracket => ("[", "]"),
+                        Delimiter::None => ("", ""),
+                    };
+
+                    self.source.push_str(open);
+                    self.write(group.stream());
+                    self.source.push_str(close);
+                }
+                TokenTree::Punct(punct) => {
+                    self.source.push(punct.as_char());
+
+                    if punct.as_char() == ',' {
+                        self.source.push(' ');
+                    }
+                }
+                word => self.source.push_str(&word.to_string()),
+            }
+
+            previous_word = word;
+        }
+    }
+
+    /// Returns span of the token at given offset in the source.
+    fn span_at(&self, offset: usize) -> Span {
+        self.spans
+            .iter()
+            .rev()
+            .find(|(start, _)| *start <= offset)
+            .map_or_else(Span::call_site, |(_, span)| *span)
+    }
+}
@@ -0,0 +1,31 @@
+use boja::{format::Hsl, Color};
+use boja_macros::color;
+
+const BLUE: Color = color!("#3366cc");
+
+#[test]
+fn string_literal() {
+    assert_eq!(BLUE, Color::from_hex_u32(0x3366cc));
+    assert_eq!(color!("3366cc80"), Color::from_hex_u32_rgba(0x3366cc80));
+    assert_eq!(color!("rgb(51, 102, 204)"), BLUE);
+}
+
+#[test]
+fn tokens() {
+    assert_eq!(color!(#3366cc), BLUE);
+    assert_eq!(color!(rgb(51, 102, 204)), BLUE);
+    assert_eq!(
+        color!(hsl(220, 60%, 50%)),
+        Color::from(Hsl::from((220.0, 60.0, 50.0)))
+    );
+    assert_eq!(
+        color!(oklab(0.5, 0.1, -0.1)),
+        "oklab(0.5, 0.1, -0.1)".parse().unwrap()
+    );
+}
+
+#[test]
+fn invalid_colors() {
+    let tests = trybuild::TestCases::new();
+    tests.compile_fail("tests/ui/*.rs");
+}
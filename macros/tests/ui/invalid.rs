@@ -0,0 +1,10 @@
+use boja::Color;
+use boja_macros::color;
+
+const WRONG_ARITY: Color = color!(rgb(51, 102));
+const OUT_OF_RANGE: Color = color!(hsl(220, 160, 50));
+const PERCENT_OUT_OF_RANGE: Color = color!(hsl(220, 60%, 150%));
+const CHANNEL_OUT_OF_RANGE: Color = color!(rgb(51, 300, 204));
+const UNKNOWN: Color = color!("hwb(220 20 20)");
+
+fn main() {}
@@ -0,0 +1,307 @@
+//! Python bindings of boja.
+//!
+//! The module is built with `maturin develop` and used as:
+//!
+//! ```python
+//! import boja
+//!
+//! color = boja.parse_color("#3366cc")
+//! assert color.convert("hsl") == "hsl(220, 60, 50)"
+//!
+//! palette = boja.Palette(["#3366cc", "oklch(0.9 0.3 140)"])
+//! assert palette.map_to_gamut().convert("hex") == ["3366cc", "62ff30"]
+//! ```
+//!
+//! Formats and gamut mapping methods are named like arguments of the command
+//! line tool, see `boja.formats()`.
+//!
+//! Tests are run in a virtual environment with:
+//!
+//! ```sh
+//! pip install maturin pytest
+//! maturin develop
+//! pytest tests
+//! ```
+
+use boja::{Color, Format, GamutMapping, Options};
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyIterator};
+
+/// Color with gamma encoded sRGB channels and alpha in range 0..1.
+#[pyclass(name = "Color", module = "boja", frozen, eq, from_py_object)]
+#[derive(Clone, Copy, PartialEq)]
+struct PyColor(Color);
+
+#[pymethods]
+impl PyColor {
+    #[new]
+    #[pyo3(signature = (red, green, blue, alpha = 1.0))]
+    fn new(red: f64, green: f64, blue: f64, alpha: f64) -> Self {
+        Self(Color::new(red, green, blue, alpha))
+    }
+
+    /// Creates color from 8 bit channels and alpha.
+    #[staticmethod]
+    #[pyo3(signature = (red, green, blue, alpha = 255))]
+    fn from_rgba8(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+        Self(Color::from_rgba(red, green, blue, alpha))
+    }
+
+    /// Parses color in any supported format.
+    #[staticmethod]
+    fn parse(input: &str) -> PyResult<Self> {
+        parse_color(input)
+    }
+
+    #[getter]
+    fn red(&self) -> f64 {
+        self.0.red()
+    }
+
+    #[getter]
+    fn green(&self) -> f64 {
+        self.0.green()
+    }
+
+    #[getter]
+    fn blue(&self) -> f64 {
+        self.0.blue()
+    }
+
+    #[getter]
+    fn alpha(&self) -> f64 {
+        self.0.alpha()
+    }
+
+    fn with_alpha(&self, alpha: f64) -> Self {
+        Self(self.0.with_alpha(alpha))
+    }
+
+    /// Returns channels clamped to sRGB gamut as 8 bit values.
+    #[pyo3(name = "to_rgba8")]
+    fn rgba8(&self) -> (u8, u8, u8, u8) {
+        let color = self.0.clamped();
+        let [red, green, blue, alpha] = [color.red(), color.green(), color.blue(), color.alpha()]
+            .map(|channel| (channel * 255.0).round() as u8);
+
+        (red, green, blue, alpha)
+    }
+
+    /// Returns `True` if the color fits into sRGB gamut.
+    fn is_in_range(&self) -> bool {
+        self.0.is_in_range()
+    }
+
+    /// Maps color into sRGB gamut using given method, e.g. `"clip"`.
+    #[pyo3(signature = (mapping = "css"))]
+    fn map_to_gamut(&self, mapping: &str) -> PyResult<Self> {
+        Ok(Self(
+            self.0.map_to_gamut(value(&GAMUT_MAPPINGS, mapping)?).0,
+        ))
+    }
+
+    /// Writes color in given format, e.g. `"oklch"`, with given number of
+    /// decimal places or default precision of the format.
+    #[pyo3(signature = (format, precision = None))]
+    fn convert(&self, format: &str, precision: Option<usize>) -> PyResult<String> {
+        let options = Options {
+            precision,
+            ..Options::default()
+        };
+
+        self.0
+            .convert_with(value(&FORMATS, format)?, &options)
+            .map_err(|error| PyValueError::new_err(error.to_string()))
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Color('{}')", self.0)
+    }
+}
+
+/// Color given either as [`PyColor`] or in textual representation.
+#[derive(FromPyObject)]
+enum ColorLike {
+    Color(PyColor),
+    Text(String),
+}
+
+impl TryFrom<ColorLike> for Color {
+    type Error = PyErr;
+
+    fn try_from(color: ColorLike) -> PyResult<Self> {
+        match color {
+            ColorLike::Color(color) => Ok(color.0),
+            ColorLike::Text(text) => Ok(parse_color(&text)?.0),
+        }
+    }
+}
+
+/// Sequence of colors converted together.
+#[pyclass(name = "Palette", module = "boja", frozen)]
+struct PyPalette {
+    colors: Vec<Color>,
+}
+
+#[pymethods]
+impl PyPalette {
+    /// Creates palette from colors or their textual representations.
+    #[new]
+    fn new(colors: Vec<ColorLike>) -> PyResult<Self> {
+        let colors = colors
+            .into_iter()
+            .map(Color::try_from)
+            .collect::<PyResult<_>>()?;
+
+        Ok(Self { colors })
+    }
+
+    /// Writes all colors in given format.
+    #[pyo3(signature = (format, precision = None))]
+    fn convert(&self, format: &str, precision: Option<usize>) -> PyResult<Vec<String>> {
+        self.colors
+            .iter()
+            .map(|color| PyColor(*color).convert(format, precision))
+            .collect()
+    }
+
+    /// Maps all colors into sRGB gamut using given method.
+    #[pyo3(signature = (mapping = "css"))]
+    fn map_to_gamut(&self, mapping: &str) -> PyResult<Self> {
+        let mapping = value(&GAMUT_MAPPINGS, mapping)?;
+        let colors = self
+            .colors
+            .iter()
+            .map(|color| color.map_to_gamut(mapping).0)
+            .collect();
+
+        Ok(Self { colors })
+    }
+
+    /// Returns indices of colors outside of sRGB gamut.
+    fn out_of_gamut(&self) -> Vec<usize> {
+        (0..self.colors.len())
+            .filter(|&index| !self.colors[index].is_in_range())
+            .collect()
+    }
+
+    fn __len__(&self) -> usize {
+        self.colors.len()
+    }
+
+    fn __getitem__(&self, index: isize) -> PyResult<PyColor> {
+        let len = self.colors.len() as isize;
+        let position = if index < 0 { index + len } else { index };
+
+        usize::try_from(position)
+            .ok()
+            .and_then(|position| self.colors.get(position))
+            .map(|color| PyColor(*color))
+            .ok_or_else(|| pyo3::exceptions::PyIndexError::new_err("palette index out of range"))
+    }
+
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        let colors = self.colors.iter().map(|color| PyColor(*color));
+
+        pyo3::types::PyList::new(py, colors)?.as_any().try_iter()
+    }
+
+    fn __repr__(&self) -> String {
+        let colors = self
+            .colors
+            .iter()
+            .map(|color| format!("'{color}'"))
+            .collect::<Vec<_>>();
+
+        format!("Palette([{}])", colors.join(", "))
+    }
+}
+
+/// Parses color in any supported format, raising `ValueError` with the
+/// reason if it is invalid.
+#[pyfunction]
+fn parse_color(input: &str) -> PyResult<PyColor> {
+    input
+        .parse::<Color>()
+        .map(PyColor)
+        .map_err(|error| PyValueError::new_err(error.to_string()))
+}
+
+/// Output formats named like arguments of the command line tool.
+const FORMATS: [(&str, Format); 29] = [
+    ("hex", Format::Hex),
+    ("rgb", Format::Rgb),
+    ("hex48", Format::Hex48),
+    ("rgb48", Format::Rgb48),
+    ("hsl", Format::Hsl),
+    ("hsv", Format::Hsv),
+    ("xyz", Format::Xyz),
+    ("xyy", Format::Xyy),
+    ("oklab", Format::Oklab),
+    ("oklch", Format::Oklch),
+    ("scrgb", Format::Scrgb),
+    ("pq", Format::Pq),
+    ("hlg", Format::Hlg),
+    ("ictcp", Format::Ictcp),
+    ("jzazbz", Format::Jzazbz),
+    ("jzczhz", Format::Jzczhz),
+    ("color", Format::Color),
+    ("rust", Format::Rust),
+    ("rust-struct", Format::RustStruct),
+    ("c", Format::C),
+    ("swiftui", Format::Swiftui),
+    ("uikit", Format::Uikit),
+    ("android", Format::Android),
+    ("flutter", Format::Flutter),
+    ("unity", Format::Unity),
+    ("glsl", Format::Glsl),
+    ("glsl-vec4", Format::GlslVec4),
+    ("compose", Format::Compose),
+    ("latex", Format::Latex),
+];
+
+/// Gamut mapping methods named like arguments of the command line tool.
+const GAMUT_MAPPINGS: [(&str, GamutMapping); 4] = [
+    ("css", GamutMapping::Css),
+    ("clip", GamutMapping::Clip),
+    ("scale", GamutMapping::Scale),
+    ("preserve-lightness", GamutMapping::PreserveLightness),
+];
+
+/// Returns names of all output formats.
+#[pyfunction]
+fn formats() -> Vec<String> {
+    names(&FORMATS)
+}
+
+/// Returns value of given name, ignoring case.
+fn value<T: Copy>(values: &[(&str, T)], name: &str) -> PyResult<T> {
+    values
+        .iter()
+        .find(|(value, _)| value.eq_ignore_ascii_case(name))
+        .map(|&(_, value)| value)
+        .ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "Unknown value '{name}', expected one of: {}",
+                names(values).join(", ")
+            ))
+        })
+}
+
+fn names<T>(values: &[(&str, T)]) -> Vec<String> {
+    values.iter().map(|(name, _)| name.to_string()).collect()
+}
+
+#[pymodule]
+#[pyo3(name = "boja")]
+fn boja_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyColor>()?;
+    m.add_class::<PyPalette>()?;
+    m.add_function(wrap_pyfunction!(parse_color, m)?)?;
+    m.add_function(wrap_pyfunction!(formats, m)?)?;
+
+    Ok(())
+}
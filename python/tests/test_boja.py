@@ -0,0 +1,65 @@
+import pytest
+
+import boja
+
+
+def test_parse_color():
+    color = boja.parse_color("#3366cc")
+
+    assert color.to_rgba8() == (51, 102, 204, 255)
+    assert color.alpha == 1.0
+    assert str(color) == "3366cc"
+    assert repr(color) == "Color('3366cc')"
+    assert boja.Color.parse("rgb(51, 102, 204)") == color
+    assert boja.Color.from_rgba8(51, 102, 204) == color
+
+
+def test_parse_color_errors():
+    with pytest.raises(ValueError, match="Expected 3 arguments"):
+        boja.parse_color("rgb(51, 102)")
+
+    with pytest.raises(ValueError):
+        boja.parse_color("hwb(220 20 20)")
+
+
+def test_convert():
+    color = boja.parse_color("#3366cc")
+
+    assert color.convert("hsl") == "hsl(220, 60, 50)"
+    assert color.convert("rgb") == "rgb(51, 102, 204)"
+    assert color.convert("hsl", precision=2) == "hsl(220.00, 60.00, 50.00)"
+
+    for format in boja.formats():
+        assert color.convert(format)
+
+    with pytest.raises(ValueError, match="Unknown value 'lab'"):
+        color.convert("lab")
+
+
+def test_gamut():
+    color = boja.parse_color("oklch(0.9 0.3 140)")
+
+    assert not color.is_in_range()
+    assert color.map_to_gamut().is_in_range()
+    assert color.map_to_gamut("clip").is_in_range()
+
+    with pytest.raises(ValueError):
+        color.map_to_gamut("unknown")
+
+
+def test_palette():
+    palette = boja.Palette(["#3366cc", boja.Color(1.0, 1.0, 1.0, 0.5), "oklch(0.9 0.3 140)"])
+
+    assert len(palette) == 3
+    assert palette[0] == boja.parse_color("#3366cc")
+    assert palette[-2].alpha == 0.5
+    assert [color.alpha for color in palette] == [1.0, 0.5, 1.0]
+    assert palette.out_of_gamut() == [2]
+    assert palette.map_to_gamut().out_of_gamut() == []
+    assert palette.convert("hex")[:2] == ["3366cc", "ffffff80"]
+
+    with pytest.raises(IndexError):
+        palette[3]
+
+    with pytest.raises(ValueError):
+        boja.Palette(["#3366cc", "not a color"])
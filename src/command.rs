@@ -1,48 +1,376 @@
 //! Command line arguments parsing.
 
-use clap::Parser;
+use std::path::PathBuf;
+
+use clap::{CommandFactory, Parser};
+
+use crate::{
+    format::{self, DEFAULT_PEAK_NITS},
+    Adaptation, AlphaPosition, Color, Config, Format, GamutMapping, HexCase, HexOptions, HexPrefix,
+    HueInterpolation, InterpolationSpace, LatexModel, Options, WhitePoint,
+};
 
 #[derive(clap::Parser, Debug)]
-#[command(author, version, about, long_about = None)]
+#[command(
+    author,
+    version,
+    about,
+    long_about = None,
+    override_usage = "boja [OPTIONS] <FMT> [INPUT]...\n       boja mix [OPTIONS] <FIRST> <SECOND>",
+    subcommand_negates_reqs = true,
+    subcommand_precedence_over_arg = true
+)]
 pub struct Command {
-    #[arg(value_enum, index = 1)]
-    fmt: Format,
+    #[command(subcommand)]
+    action: Option<Action>,
+
+    #[arg(value_enum, index = 1, required = true)]
+    fmt: Option<Format>,
 
     #[arg(
         index = 2,
         help = "Input color in one of the supported representations."
     )]
     input: Vec<String>,
+
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        default_value_t = LatexModel::RgbInt,
+        help = "Color model used by the LaTeX format."
+    )]
+    latex_model: LatexModel,
+
+    #[arg(
+        long,
+        global = true,
+        default_value = "color",
+        help = "Name of the color in formats that define named colors, i.e. LaTeX and Android XML."
+    )]
+    name: String,
+
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        default_value_t = HexCase::Lower,
+        help = "Letter case of hexadecimal digits."
+    )]
+    hex_case: HexCase,
+
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        default_value_t = HexPrefix::None,
+        help = "Prefix of hexadecimal colors."
+    )]
+    hex_prefix: HexPrefix,
+
+    #[arg(
+        long,
+        global = true,
+        help = "Use 3 or 4 digit hexadecimal shorthand when it is lossless, e.g. fff."
+    )]
+    hex_short: bool,
+
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        default_value_t = AlphaPosition::Last,
+        help = "Placement of alpha in hexadecimal colors with alpha, both in input and output."
+    )]
+    hex_alpha: AlphaPosition,
+
+    #[arg(
+        long,
+        global = true,
+        help = "Number of decimal places in formats with decimal values. Defaults to format's own precision."
+    )]
+    precision: Option<usize>,
+
+    #[arg(
+        long,
+        global = true,
+        default_value_t = DEFAULT_PEAK_NITS,
+        value_parser = parse_peak_nits,
+        help = "Peak luminance in nits of HDR formats, corresponding to color channel value of 1."
+    )]
+    peak_nits: f64,
+
+    #[arg(
+        long,
+        global = true,
+        default_value = "srgb",
+        help = "RGB color space of the color format, predefined by CSS (e.g. display-p3) or defined in --config."
+    )]
+    space: String,
+
+    #[arg(
+        long,
+        global = true,
+        help = "TOML file with definitions of custom RGB color spaces."
+    )]
+    config: Option<PathBuf>,
+
+    #[arg(
+        long,
+        global = true,
+        default_value_t = WhitePoint::D65,
+        help = "Reference white of XYZ based formats: A, C, D50, D55, D65, D75, E, F2, F7, F11 or chromaticity x,y."
+    )]
+    white_point: WhitePoint,
+
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        default_value_t = Adaptation::Bradford,
+        help = "Chromatic adaptation transform used to convert between white points."
+    )]
+    adaptation: Adaptation,
+
+    #[arg(
+        long,
+        global = true,
+        value_enum,
+        default_value_t = GamutMapping::Css,
+        help = "Method of fitting colors outside of sRGB gamut into formats limited to it."
+    )]
+    gamut_mapping: GamutMapping,
+}
+
+#[derive(clap::Subcommand, Debug)]
+enum Action {
+    #[command(about = "Mix two colors like CSS color-mix().")]
+    Mix(Mix),
+}
+
+#[derive(clap::Args, Debug)]
+struct Mix {
+    #[arg(help = "Color mixed into, in one of the supported representations.")]
+    first: String,
+
+    #[arg(help = "Color mixed in, in one of the supported representations.")]
+    second: String,
+
+    #[arg(
+        long,
+        default_value_t = 0.5,
+        value_parser = parse_ratio,
+        help = "Amount of the second color in the result, in range 0..1 or in percent, e.g. 25%."
+    )]
+    ratio: f64,
+
+    #[arg(
+        long = "in",
+        value_name = "SPACE",
+        value_enum,
+        default_value_t = InterpolationSpace::Oklab,
+        help = "Color space in which colors are mixed."
+    )]
+    interpolation: InterpolationSpace,
+
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = HueInterpolation::Shorter,
+        help = "Interpolation of hues when mixing in cylindrical spaces, e.g. oklch."
+    )]
+    hue_interpolation: HueInterpolation,
+
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = Format::Hex,
+        help = "Format of the mixed color."
+    )]
+    format: Format,
 }
 
 impl Command {
     pub fn init() -> Self {
-        Command::parse()
+        Command::try_init_from(std::env::args_os()).unwrap_or_else(|error| error.exit())
     }
 
+    /// Parses arguments, rejecting format given before a subcommand, which
+    /// takes format of its output as an option instead.
+    fn try_init_from<I, T>(args: I) -> Result<Self, clap::Error>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<std::ffi::OsString> + Clone,
+    {
+        let command = Command::try_parse_from(args)?;
+
+        if command.action.is_some() && command.fmt.is_some() {
+            return Err(Command::command().error(
+                clap::error::ErrorKind::ArgumentConflict,
+                "format must not be given before a subcommand, use its --format option instead",
+            ));
+        }
+
+        Ok(command)
+    }
+
+    /// Returns the input color, which is the first color of `mix`.
     pub fn input(&self) -> String {
-        self.input.concat()
+        match &self.action {
+            Some(Action::Mix(mix)) => mix.first.clone(),
+            None => self.input.concat(),
+        }
     }
 
     pub fn fmt(&self) -> Format {
-        self.fmt
+        match &self.action {
+            Some(Action::Mix(mix)) => mix.format,
+            None => self.fmt.expect("format is required without subcommand"),
+        }
+    }
+
+    /// Returns color to be mixed into the input color, if any.
+    pub fn mix(&self) -> Option<&str> {
+        match &self.action {
+            Some(Action::Mix(mix)) => Some(&mix.second),
+            None => None,
+        }
+    }
+
+    /// Mixes `color` with `other` using the options of `mix`.
+    pub fn mixed(&self, color: Color, other: Color) -> Color {
+        match &self.action {
+            Some(Action::Mix(mix)) => {
+                color.mix_with(other, mix.ratio, mix.interpolation, mix.hue_interpolation)
+            }
+            None => color,
+        }
+    }
+
+    /// Loads configuration file if one is given, or returns default
+    /// configuration with predefined color spaces only.
+    pub fn config(&self) -> anyhow::Result<Config> {
+        match &self.config {
+            Some(path) => Config::load(path),
+            None => Ok(Config::default()),
+        }
+        .map(|config| {
+            config
+                .with_white_point(self.white_point)
+                .with_adaptation(self.adaptation)
+                .with_hex_alpha(self.hex_alpha)
+        })
     }
+
+    pub fn options(&self, config: &Config) -> anyhow::Result<Options> {
+        let space = config
+            .space(&self.space)
+            .ok_or_else(|| anyhow::anyhow!("Unknown color space '{}'", self.space))?;
+
+        if self.fmt() == Format::Latex {
+            format::check_color_name(&self.name)?;
+        }
+
+        Ok(Options {
+            latex_model: self.latex_model,
+            name: self.name.clone(),
+            hex: HexOptions {
+                case: self.hex_case,
+                prefix: self.hex_prefix,
+                short: self.hex_short,
+                alpha: self.hex_alpha,
+            },
+            precision: self.precision,
+            peak_nits: self.peak_nits,
+            space: space.clone(),
+            white_point: self.white_point,
+            adaptation: self.adaptation,
+            gamut_mapping: self.gamut_mapping,
+        })
+    }
+}
+
+/// Parses peak luminance in nits, which must be positive and finite.
+fn parse_peak_nits(value: &str) -> anyhow::Result<f64> {
+    Ok(format::check_peak(value.parse()?)?)
+}
+
+/// Parses mixing ratio in range 0..1, or in percent.
+fn parse_ratio(value: &str) -> anyhow::Result<f64> {
+    let ratio = match value.strip_suffix('%') {
+        Some(percent) => percent.trim().parse::<f64>()? / 100.0,
+        None => value.parse::<f64>()?,
+    };
+
+    anyhow::ensure!(
+        (0.0..=1.0).contains(&ratio),
+        "Ratio must be in range 0..1 or 0%..100%"
+    );
+
+    Ok(ratio)
 }
 
-#[derive(Clone, Copy, Debug, clap::ValueEnum)]
-pub enum Format {
-    #[value(help = "Hexadecimal representation of RGB color. Example for white: ffffff")]
-    Hex,
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use crate::{Config, InterpolationSpace};
 
-    #[value(help = "RGB representation of color. Example for white: rgb(255, 255, 255)")]
-    Rgb,
+    use super::{Action, Command};
 
-    #[value(help = "HSL representation of color. Example for white hsl(0, 0, 100)")]
-    Hsl,
+    #[test]
+    fn mix() {
+        let command = Command::try_init_from([
+            "boja",
+            "mix",
+            "#f00",
+            "#00f",
+            "--in",
+            "srgb",
+            "--space",
+            "display-p3",
+            "--precision",
+            "2",
+        ])
+        .unwrap();
+
+        let Some(Action::Mix(mix)) = &command.action else {
+            panic!("mix is a subcommand");
+        };
+        assert_eq!(mix.interpolation, InterpolationSpace::Srgb);
+        assert_eq!(command.space, "display-p3");
+        assert_eq!(command.precision, Some(2));
+
+        let error = Command::try_init_from(["boja", "rgb", "mix", "#f00", "#00f"]).unwrap_err();
+        assert_eq!(error.kind(), clap::error::ErrorKind::ArgumentConflict);
+    }
 
-    #[value(help = "HSV representation of color. Example for white: hsv(0, 0, 100)")]
-    Hsv,
+    #[test]
+    fn peak_nits() {
+        let parse = |peak: &str| {
+            Command::try_parse_from(["boja", "hlg", &format!("--peak-nits={peak}"), "fff"])
+        };
 
-    #[value(help = "CMYK representation of color. Example for white: cmyk(0, 0, 0, 0)")]
-    Cmyk,
+        assert_eq!(parse("203").unwrap().peak_nits, 203.0);
+
+        for peak in ["0", "-100", "inf", "NaN"] {
+            let error = parse(peak).unwrap_err().to_string();
+            assert!(
+                error.contains("peak luminance must be greater than 0"),
+                "{error}"
+            );
+        }
+    }
+
+    #[test]
+    fn name_is_checked_for_latex_only() {
+        let options = |fmt: &str| {
+            Command::try_parse_from(["boja", fmt, "--name", "brand_blue", "fff"])
+                .unwrap()
+                .options(&Config::default())
+        };
+
+        assert_eq!(options("hex").unwrap().name, "brand_blue");
+        assert!(options("latex").is_err());
+    }
 }
@@ -1,7 +0,0 @@
-mod hsl;
-mod hsv;
-mod rgb;
-
-pub(crate) use hsl::*;
-pub(crate) use hsv::*;
-pub(crate) use rgb::*;
@@ -1,127 +1,59 @@
+//! Conversion of colors between textual formats.
+//!
+//! Colors are parsed from any supported format into [`Color`], which can be
+//! written into another format as string using [`Color::convert`], or
+//! converted into typed formats from [`format`](mod@format) module, e.g.:
+//!
+//! ```
+//! # #[cfg(feature = "std")] {
+//! use boja::{format::Hsl, parse_color, Format};
+//!
+//! let color = parse_color("#3366cc").unwrap();
+//! assert_eq!(color.convert(Format::Rgb).unwrap(), "rgb(51, 102, 204)");
+//!
+//! let hsl = Hsl::from(color);
+//! assert_eq!(hsl.hue().round(), 220.0);
+//! assert_eq!(hsl.lightness().round(), 50.0);
+//! # }
+//! ```
+//!
+//! Colors, formats and the parser are defined in boja-core and re-exported
+//! here.
+//!
+//! # Features
+//!
+//! - `cli` (default): the `boja` command line tool, enables `std`.
+//! - `std`: parsing of colors and configuration files. Without it, the crate
+//!   is `no_std` and only needs an allocator, so that colors can be created
+//!   and converted between formats e.g. in firmware.
+//! - `serde`: `Serialize` and `Deserialize` for [`Color`] and all formats.
+//! - `image`: conversions between [`Color`] and `Rgb` and `Rgba` pixels of the
+//!   image crate with 8 and 16 bit channels, enables `std`.
+//! - `palette`: conversions between [`Color`] and `Srgb` and `Srgba` of the
+//!   palette crate, and between formats and their counterparts in palette,
+//!   e.g. [`format::Oklch`] and `palette::Oklch`.
+//! - `macros`: the `color!` macro parsing color literals at compile time,
+//!   e.g. `color!(hsl(220, 60%, 50%))`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "cli")]
 mod command;
-pub mod error;
-mod format;
-mod parser;
 
-use format::{Hsl, Hsv, Rgb};
-use itertools::Itertools;
-use std::error::Error;
-
-pub use command::*;
-pub use parser::parse_color;
-
-/// Internal color representation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Color {
-    red: u8,
-    green: u8,
-    blue: u8,
-}
-
-impl Color {
-    pub fn convert(&self, fmt: Format) -> String {
-        match fmt {
-            Format::Hex => self.convert_to_hex(),
-            Format::Rgb => self.convert_to_rgb(),
-            Format::Hsl => self.convert_to_hsl(),
-            Format::Hsv => self.convert_to_hsv(),
-            Format::Cmyk => self.convert_to_cmyk(),
-        }
-    }
-
-    fn convert_to_hex(self) -> String {
-        Rgb::from(self).to_hex()
-    }
-
-    fn convert_to_rgb(self) -> String {
-        Rgb::from(self).to_rgb()
-    }
-
-    fn convert_to_hsl(self) -> String {
-        Hsl::from(Rgb::from(self)).to_string()
-    }
-
-    fn convert_to_hsv(self) -> String {
-        Hsv::from(Rgb::from(self)).to_string()
-    }
-
-    fn convert_to_cmyk(self) -> String {
-        todo!()
-    }
-}
-
-impl TryFrom<&[char]> for Color {
-    type Error = Box<dyn Error>;
-
-    fn try_from(value: &[char]) -> Result<Self, Self::Error> {
-        let value: Vec<char> = if value.len() == 3 {
-            value.iter().flat_map(|ch| [*ch, *ch]).collect()
-        } else {
-            value.to_vec()
-        };
-
-        match value.len() {
-            6 => {
-                let red = u8::from_str_radix(&value[0..2].iter().collect::<String>(), 16)?;
-                let green = u8::from_str_radix(&value[2..4].iter().collect::<String>(), 16)?;
-                let blue = u8::from_str_radix(&value[4..6].iter().collect::<String>(), 16)?;
-
-                Ok(Self { red, green, blue })
-            }
-            _ => todo!(),
-        }
-    }
-}
-
-impl TryFrom<&[Vec<char>]> for Color {
-    type Error = anyhow::Error;
-
-    fn try_from(value: &[Vec<char>]) -> Result<Self, Self::Error> {
-        if value.len() != 3 {
-            anyhow::bail!("Bad input provided: {:?}", value)
-        }
-
-        todo!()
-    }
-}
-
-impl TryFrom<&[&str]> for Color {
-    type Error = anyhow::Error;
-
-    fn try_from(value: &[&str]) -> Result<Self, Self::Error> {
-        let (red, green, blue) = value
-            .iter()
-            .tuple_windows()
-            .next()
-            .ok_or(anyhow::anyhow!("Bad input provided {:?}", value))?;
-
-        Ok(Color {
-            red: red.parse()?,
-            green: green.parse()?,
-            blue: blue.parse()?,
-        })
-    }
-}
-
-impl TryFrom<&[String]> for Color {
-    type Error = anyhow::Error;
-
-    fn try_from(value: &[String]) -> Result<Self, Self::Error> {
-        let (red, green, blue) = value
-            .iter()
-            .map(String::as_str)
-            .tuple_windows()
-            .next()
-            .ok_or(anyhow::anyhow!("Bad input: {:?}", value))?;
-
-        Color::try_from(&[red, green, blue][..])
-    }
-}
-
-impl From<Rgb> for Color {
-    fn from(rgb: Rgb) -> Self {
-        let (red, green, blue) = rgb.into_tuple();
-
-        Self { red, green, blue }
-    }
-}
+pub use boja_core::*;
+
+/// Color literal validated at compile time, e.g.:
+///
+/// ```
+/// use boja::{color, Color};
+///
+/// const BLUE: Color = color!("#3366cc");
+/// const ACCENT: Color = color!(oklch(0.6 0.1 250));
+///
+/// assert_eq!(BLUE, Color::from_hex_u32(0x3366cc));
+/// assert_eq!(ACCENT.to_string(), "4f84ba");
+/// ```
+#[cfg(feature = "macros")]
+pub use boja_macros::color;
+#[cfg(feature = "cli")]
+pub use command::Command;
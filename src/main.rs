@@ -1,16 +1,48 @@
 use ariadne::{sources, Label, Report, ReportKind};
-use boja::{error::CustomError, Command};
+use boja::{error::CustomError, Command, GamutMapping};
+use clap::ValueEnum;
 
 fn main() {
     let cfg = Command::init();
     let input = cfg.input();
 
-    let res = boja::parse_color(&input);
+    let (config, options) = match cfg.config().and_then(|config| {
+        let options = cfg.options(&config)?;
+        Ok((config, options))
+    }) {
+        Ok(configured) => configured,
+        Err(err) => {
+            eprintln!("Error: {err:#}");
+            std::process::exit(1);
+        }
+    };
+
+    let res = boja::parse_color_with(&input, &config);
+
+    let res = match (res, cfg.mix()) {
+        (Ok(col), Some(mix)) => match boja::parse_color_with(mix, &config) {
+            Ok(other) => Ok(cfg.mixed(col, other)),
+            Err(errs) => {
+                errs.into_iter().for_each(|err| pretty_print(mix, err));
+                return;
+            }
+        },
+        (res, _) => res,
+    };
 
     match res {
         Ok(col) => {
-            let output = col.convert(cfg.fmt());
-            println!("{output}");
+            if cfg.fmt().is_bounded() && !col.is_in_range() {
+                warn_gamut_mapped(&input, options.gamut_mapping);
+            }
+
+            match col.convert_with(cfg.fmt(), &options) {
+                Ok(output) => println!("{output}"),
+                Err(err) => {
+                    eprintln!("Error: {err}");
+                    std::process::exit(1);
+                }
+            }
         }
         Err(errs) => errs.into_iter().for_each(|err| pretty_print(&input, err)),
     };
@@ -27,3 +59,18 @@ fn pretty_print(input: &str, error: CustomError) {
         .eprint(sources([("stdin", input)]))
         .unwrap();
 }
+
+fn warn_gamut_mapped(input: &str, mapping: GamutMapping) {
+    let mapping = mapping.to_possible_value().expect("no skipped values");
+
+    Report::<(&str, _)>::build(ReportKind::Warning, "stdin", 0)
+        .with_message("Color is outside of sRGB gamut")
+        .with_label(Label::new(("stdin", 0..input.len())).with_message(format!(
+            "this color was mapped to fit the output format using '{}' gamut mapping",
+            mapping.get_name()
+        )))
+        .with_help("use 'scrgb', 'xyy' or 'oklch' format to keep the full range")
+        .finish()
+        .eprint(sources([("stdin", input)]))
+        .unwrap();
+}